@@ -0,0 +1,216 @@
+//! Attributes (`#[...]` and `#![...]`) and doc comments.
+//!
+//! The lexer turns doc comments into attributes, `/// text` becomes `#[doc = " text"]` and
+//! `//! text` becomes `#![doc = " text"]`. [`DocComment`] parses both forms and gives access
+//! to the text. [`OuterAttribute`] and [`InnerAttribute`] parse any attribute, the content is
+//! parsed by `C` which defaults to a [`TokenStream`] that takes everything within the
+//! brackets.
+#![allow(clippy::module_name_repetitions)]
+
+use crate::{
+    Assign, Bang, BracketGroupContaining, CachedIdent, Cons, Error, LiteralString, Nothing, Parse,
+    Parser, Pound, PunctSpaced, RefineErr, Result, Spacing, ToTokens, TokenIter, TokenStream,
+};
+
+/// An outer attribute `#[...]`, the content within the brackets is parsed by `C`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#"
+///     /// documented
+///     #[derive(Debug)]
+///     struct Foo;
+/// "#.to_token_iter();
+///
+/// let attributes = Vec::<OuterAttribute>::parse(&mut token_iter).unwrap();
+/// assert_eq!(attributes.len(), 2);
+/// assert_tokens_eq!(attributes[1], "#[derive(Debug)]");
+/// assert_tokens_eq!(attributes[1].content.content, "derive(Debug)");
+/// ```
+#[derive(Debug, Clone)]
+pub struct OuterAttribute<C = TokenStream> {
+    /// The `#`
+    pub pound: Pound,
+    /// The bracketed content
+    pub content: BracketGroupContaining<C>,
+}
+
+impl<C> OuterAttribute<C> {
+    /// Creates a new `OuterAttribute` with the given content.
+    pub const fn new(content: C) -> Self {
+        Self {
+            pound: Pound::new(),
+            content: BracketGroupContaining::new(content),
+        }
+    }
+}
+
+impl<C: Parse> Parser for OuterAttribute<C> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            pound: Pound::parser(tokens).refine_err::<Self>()?,
+            content: BracketGroupContaining::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl<C: ToTokens> ToTokens for OuterAttribute<C> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.pound.to_tokens(tokens);
+        self.content.to_tokens(tokens);
+    }
+}
+
+/// An inner attribute `#![...]`, the content within the brackets is parsed by `C`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "#![allow(dead_code)] #[outer]".to_token_iter();
+///
+/// let inner = <InnerAttribute>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(inner.content.content, "allow(dead_code)");
+/// assert!(<InnerAttribute>::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct InnerAttribute<C = TokenStream> {
+    /// The `#`, the lexer joins it with the `!`
+    pub pound: PunctSpaced<'#'>,
+    /// The `!`
+    pub bang: Bang,
+    /// The bracketed content
+    pub content: BracketGroupContaining<C>,
+}
+
+impl<C> InnerAttribute<C> {
+    /// Creates a new `InnerAttribute` with the given content.
+    pub const fn new(content: C) -> Self {
+        Self {
+            pound: PunctSpaced::new(Spacing::Joint),
+            bang: Bang::new(),
+            content: BracketGroupContaining::new(content),
+        }
+    }
+}
+
+impl<C: Parse> Parser for InnerAttribute<C> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            pound: PunctSpaced::parser(tokens).refine_err::<Self>()?,
+            bang: Bang::parser(tokens).refine_err::<Self>()?,
+            content: BracketGroupContaining::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl<C: ToTokens> ToTokens for InnerAttribute<C> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.pound.to_tokens(tokens);
+        self.bang.to_tokens(tokens);
+        self.content.to_tokens(tokens);
+    }
+}
+
+/// The `doc = "text"` content of a doc attribute.
+pub type DocContent = Cons<CachedIdent, Assign, LiteralString>;
+
+/// A doc comment, either an outer `/// text` or `#[doc = "text"]`, or an inner `//! text` or
+/// `#![doc = "text"]`. Other attributes are rejected. The text is kept as written, the lexer
+/// keeps the space after `///`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#"
+///     //! inner
+///     /// first
+///     #[doc = "second"]
+///     #[derive(Debug)]
+/// "#.to_token_iter();
+///
+/// let docs = Vec::<DocComment>::parse(&mut token_iter).unwrap();
+/// assert!(docs[0].is_inner());
+/// assert_eq!(docs[1].text(), " first");
+/// assert_eq!(docs[2].text(), "second");
+/// assert_eq!(docs.len(), 3);
+///
+/// // carry documentation to generated code
+/// let doc = DocComment::new(" generated");
+/// assert_tokens_eq!(doc, r#"#[doc = " generated"]"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocComment {
+    /// The `#`, joint with the `!` of inner doc comments
+    pub pound: PunctSpaced<'#'>,
+    /// The `!` of inner doc comments
+    pub bang: Option<Bang>,
+    /// The bracketed `doc = "text"`
+    pub content: BracketGroupContaining<DocContent>,
+}
+
+impl DocComment {
+    /// Creates a new outer `#[doc = "text"]` doc comment.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            pound: PunctSpaced::new(Spacing::Alone),
+            bang: None,
+            content: BracketGroupContaining::new(Cons {
+                first: CachedIdent::new("doc"),
+                second: Assign::new(),
+                third: LiteralString::from_str(text),
+                fourth: Nothing,
+            }),
+        }
+    }
+
+    /// Creates a new inner `#![doc = "text"]` doc comment.
+    #[must_use]
+    pub fn new_inner(text: &str) -> Self {
+        Self {
+            pound: PunctSpaced::new(Spacing::Joint),
+            bang: Some(Bang::new()),
+            ..Self::new(text)
+        }
+    }
+
+    /// Gets the text of the doc comment.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.content.content.third.unescaped()
+    }
+
+    /// Returns `true` for inner `//!` doc comments.
+    #[must_use]
+    pub const fn is_inner(&self) -> bool {
+        self.bang.is_some()
+    }
+}
+
+impl Parser for DocComment {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let doc = Self {
+            pound: PunctSpaced::parser(tokens).refine_err::<Self>()?,
+            bang: Option::<Bang>::parser(tokens).refine_err::<Self>()?,
+            content: BracketGroupContaining::parser(tokens).refine_err::<Self>()?,
+        };
+        if doc.content.content.first == "doc" {
+            Ok(doc)
+        } else {
+            Error::other(at, tokens, "expected a doc attribute".into())
+        }
+    }
+}
+
+impl ToTokens for DocComment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.pound.to_tokens(tokens);
+        self.bang.to_tokens(tokens);
+        self.content.to_tokens(tokens);
+    }
+}
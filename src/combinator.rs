@@ -2,10 +2,14 @@
 //! parsers on the fly without the need to define custom structures. This is done by using the
 //! [`Cons`] and [`Either`] types. The [`Cons`] type is used to define a parser that is a
 //! conjunction of two to four other parsers, while the [`Either`] type is used to define a
-//! parser that is a disjunction of two to four other parsers.
+//! parser that is a disjunction of two to four other parsers. [`Unordered`] parses a tuple
+//! of parsers in any order.
 
 use crate::{Error, Invalid, Nothing, Parse, Parser, Result, ToTokens, TokenIter, TokenStream};
 
+use std::marker::PhantomData;
+use std::ops::Deref;
+
 /// Conjunctive `A` followed by `B` and optional `C` and `D`
 /// When `C` and `D` are not used, they are set to [`Nothing`].
 #[derive(Clone, Default)]
@@ -394,6 +398,167 @@ where
     }
 }
 
+/// Parses the members of the tuple `T` in any order, each member at most once. Members are
+/// separated by an optional delimiter `D` which defaults to [`Nothing`]. At each position the
+/// members are tried in order, a member only matches when it consumes tokens. When a member
+/// matches a second time a error is returned. Members that did not match are parsed at the
+/// end without consuming tokens, thus members such as `Option<T>` or [`Nothing`] are
+/// optional, anything else is required and reported as missing.  `Unordered` is implemented
+/// for tuples with 2 to 4 members.
+///
+/// [`ToTokens`] emits the members in canonical (declaration) order, members that emit no
+/// tokens are skipped and the remaining ones are separated by a default constructed `D`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// keyword!{Skip = "skip"; Name = "name"; Rename = "rename";}
+///
+/// type Clauses = Unordered<(
+///     Cons<Name, Assign, LiteralString>,
+///     Option<Skip>,
+///     Option<Cons<Rename, Assign, LiteralString>>,
+/// ), Comma>;
+///
+/// let mut token_iter = r#"rename = "y", skip, name = "x""#.to_token_iter();
+/// let clauses = Clauses::parse_all(&mut token_iter).unwrap();
+/// assert!(clauses.1.is_some());
+/// assert_tokens_eq!(clauses, r#"name = "x", skip, rename = "y""#);
+///
+/// // duplicates are an error
+/// let mut token_iter = r#"name = "x", skip, skip"#.to_token_iter();
+/// assert!(Clauses::parse(&mut token_iter).is_err());
+///
+/// // missing required members are an error
+/// let mut token_iter = r#"skip"#.to_token_iter();
+/// assert!(Clauses::parse(&mut token_iter).is_err());
+/// ```
+pub struct Unordered<T, D = Nothing>(pub T, PhantomData<D>);
+
+impl<T, D> Unordered<T, D> {
+    /// Create a new `Unordered` from a tuple of members.
+    pub const fn new(members: T) -> Self {
+        Self(members, PhantomData)
+    }
+
+    /// Deconstructs `self` and returns the tuple of members.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, D> Deref for Unordered<T, D> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Clone, D> Clone for Unordered<T, D> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+macro_rules! impl_unordered {
+    ($($member:ident: $index:tt),+) => {
+        impl<$($member: Parse,)+ D: Parse> Parser for Unordered<($($member,)+), D> {
+            fn parser(tokens: &mut TokenIter) -> Result<Self> {
+                let mut slots = ($(Option::<$member>::None,)+);
+                loop {
+                    let at = tokens.clone().next();
+                    let start = tokens.counter();
+                    let mut matched = false;
+                    $(
+                        if !matched {
+                            let mut ptokens = tokens.clone();
+                            if let Ok(value) = $member::parser(&mut ptokens) {
+                                if ptokens.counter() > start {
+                                    if slots.$index.is_some() {
+                                        return Error::other(
+                                            at,
+                                            &ptokens,
+                                            format!(
+                                                "duplicate {}",
+                                                std::any::type_name::<$member>()
+                                            ),
+                                        );
+                                    }
+                                    slots.$index = Some(value);
+                                    *tokens = ptokens;
+                                    matched = true;
+                                }
+                            }
+                        }
+                    )+
+                    if !matched || Option::<D>::parser(tokens)?.is_none() {
+                        break;
+                    }
+                }
+
+                Ok(Self(
+                    ($(
+                        if let Some(value) = slots.$index {
+                            value
+                        } else {
+                            let at = tokens.clone().next();
+                            let start = tokens.counter();
+                            let mut ptokens = tokens.clone();
+                            match $member::parser(&mut ptokens) {
+                                Ok(value) if ptokens.counter() == start => value,
+                                _ => {
+                                    return Error::other(
+                                        at,
+                                        tokens,
+                                        format!(
+                                            "missing required {}",
+                                            std::any::type_name::<$member>()
+                                        ),
+                                    )
+                                }
+                            }
+                        },
+                    )+),
+                    PhantomData,
+                ))
+            }
+        }
+
+        impl<$($member: ToTokens,)+ D: ToTokens + Default> ToTokens
+            for Unordered<($($member,)+), D>
+        {
+            fn to_tokens(&self, tokens: &mut TokenStream) {
+                let members = [$(self.0.$index.to_token_stream()),+];
+                for (n, member) in members.into_iter().filter(|m| !m.is_empty()).enumerate() {
+                    if n > 0 {
+                        D::default().to_tokens(tokens);
+                    }
+                    tokens.extend(member);
+                }
+            }
+        }
+    };
+}
+
+impl_unordered! {A: 0, B: 1}
+impl_unordered! {A: 0, B: 1, C: 2}
+impl_unordered! {A: 0, B: 1, C: 2, E: 3}
+
+#[mutants::skip]
+impl<T: std::fmt::Debug, D> std::fmt::Debug for Unordered<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple(&format!(
+            "Unordered<{}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<D>()
+        ))
+        .field(&self.0)
+        .finish()
+    }
+}
+
 #[test]
 fn test_either_into_tt() {
     use crate::{LiteralInteger, TokenTree};
@@ -1,13 +1,24 @@
 //! This module provides parsers for types that contain possibly multiple values. This
-//! includes stdlib types like [`Option`], [`Vec`], [`Box`], [`Rc`], [`RefCell`] and types
-//! for delimited and repeated values with numbered repeats.
+//! includes stdlib types like [`Option`], [`Vec`], [`Box`], [`Rc`], [`Arc`], [`Cow`], the
+//! cells [`Cell`], [`RefCell`], [`Mutex`], [`RwLock`], the collections [`VecDeque`],
+//! [`HashSet`], [`BTreeSet`], [`HashMap`], [`BTreeMap`] and types for delimited and repeated
+//! values with numbered repeats. [`CountedRepeats`] takes the number of repeats from the
+//! input. Arrays `[T; N]` and [`DelimitedArray`] parse a fixed number of elements,
+//! [`NonEmptyVec`] at least one element.
 
 use crate::{
-    Colon, Comma, Delimited, Dot, Error, Nothing, Parse, Parser, PathSep, RefineErr, Result,
-    Semicolon, ToTokens, TokenIter, TokenStream,
+    Assign, Colon, Comma, Cons, Delimited, Dot, Error, LiteralInteger, Nothing, Parse, Parser,
+    PathSep, RefineErr, Result, Semicolon, ToTokens, TokenIter, TokenStream,
 };
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::{btree_map, hash_map, BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+    rc::Rc,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+};
 
 /// Zero or One of T.
 impl<T: Parse> Parser for Option<T> {
@@ -40,7 +51,9 @@ impl<T: Parse> Parser for Vec<T> {
 
 impl<T: ToTokens> ToTokens for Vec<T> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.iter().for_each(|value| value.to_tokens(tokens));
+        for value in self {
+            value.to_tokens(tokens);
+        }
     }
 }
 
@@ -98,9 +111,14 @@ pub trait RangedRepeats: Sized {
     }
 }
 
+/// Upper limit for the elements preallocated by [`RangedRepeats`]. The minimum may come from
+/// untrusted input such as the count of a [`CountedRepeats`], larger vectors grow as elements
+/// are parsed.
+const PREALLOCATE_LIMIT: usize = 1024;
+
 impl<T: Parse> RangedRepeats for Vec<T> {
     fn parse_repeats(tokens: &mut TokenIter, min: usize, max: usize) -> Result<Self> {
-        let mut output = Vec::with_capacity(min);
+        let mut output = Vec::with_capacity(min.min(PREALLOCATE_LIMIT));
         let mut at = tokens.clone().next();
         for _ in 0..max {
             at = tokens.clone().next();
@@ -166,6 +184,370 @@ impl<T: ToTokens> ToTokens for RefCell<T> {
     }
 }
 
+/// Arc a parseable entity. This is the thread safe sibling of `Rc`.
+impl<T: Parse> Parser for Arc<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Arc::new(T::parser(tokens).refine_err::<Self>()?))
+    }
+}
+
+impl<T: ToTokens> ToTokens for Arc<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens);
+    }
+}
+
+/// Put any parseable entity in a `Cell`. Emitting tokens temporary takes the value out of the
+/// cell, thus it must implement `Default`.
+impl<T: Parse> Parser for Cell<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Cell::new(T::parser(tokens).refine_err::<Self>()?))
+    }
+}
+
+impl<T: ToTokens + Default> ToTokens for Cell<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let value = self.take();
+        value.to_tokens(tokens);
+        self.set(value);
+    }
+}
+
+/// Put any parseable entity in a `Mutex`. A poisoned mutex is still emitted by `ToTokens`.
+impl<T: Parse> Parser for Mutex<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Mutex::new(T::parser(tokens).refine_err::<Self>()?))
+    }
+}
+
+impl<T: ToTokens> ToTokens for Mutex<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .to_tokens(tokens);
+    }
+}
+
+/// Put any parseable entity in a `RwLock`. A poisoned lock is still emitted by `ToTokens`.
+impl<T: Parse> Parser for RwLock<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(RwLock::new(T::parser(tokens).refine_err::<Self>()?))
+    }
+}
+
+impl<T: ToTokens> ToTokens for RwLock<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .to_tokens(tokens);
+    }
+}
+
+/// Parses the owned variant of a `Cow`. Allows to mix parsed entities with borrowed ones that
+/// are created programmatically.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// # use std::borrow::Cow;
+/// let mut token_iter = "foo".to_token_iter();
+///
+/// let cow = Cow::<str>::parse(&mut token_iter).unwrap();
+/// assert!(matches!(cow, Cow::Owned(_)));
+/// assert_tokens_eq!(cow, "foo");
+/// ```
+impl<T: ToOwned + ?Sized> Parser for Cow<'_, T>
+where
+    T::Owned: Parse,
+{
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Cow::Owned(T::Owned::parser(tokens).refine_err::<Self>()?))
+    }
+}
+
+impl<T: ToOwned + ToTokens + ?Sized> ToTokens for Cow<'_, T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens);
+    }
+}
+
+/// Any number of T, same as `Vec<T>`.
+impl<T: Parse> Parser for VecDeque<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Vec::<T>::parser(tokens)?.into())
+    }
+}
+
+impl<T: ToTokens> ToTokens for VecDeque<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for value in self {
+            value.to_tokens(tokens);
+        }
+    }
+}
+
+/// Parses any number of `T` and inserts them with `insert`. `insert` returns the string
+/// representation of a duplicate, this is reported as error at the duplicates position.
+fn parse_unique<T: Parse>(
+    tokens: &mut TokenIter,
+    mut insert: impl FnMut(T) -> Option<String>,
+) -> Result<()> {
+    loop {
+        let at = tokens.clone().next();
+        let Ok(value) = T::parse(tokens) else {
+            return Ok(());
+        };
+        if let Some(duplicate) = insert(value) {
+            return Error::other(at, tokens, format!("duplicate `{duplicate}`"));
+        }
+    }
+}
+
+/// Any number of unique `T`, parsed like `Vec<T>`. Duplicates are an error. `ToTokens`
+/// emits the elements ordered by their string representation.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// # use std::collections::HashSet;
+/// let mut token_iter = "c a b".to_token_iter();
+/// let set = HashSet::<Ident>::parse(&mut token_iter).unwrap();
+/// assert_eq!(set.len(), 3);
+/// assert_tokens_eq!(set, "a b c");
+///
+/// let mut token_iter = "a b a".to_token_iter();
+/// assert!(HashSet::<Ident>::parse(&mut token_iter).is_err());
+/// ```
+impl<T: Parse + ToTokens + Hash + Eq, H: BuildHasher + Default> Parser for HashSet<T, H> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut set = HashSet::<T, H>::default();
+        parse_unique(tokens, |value| {
+            if set.contains(&value) {
+                Some(value.tokens_to_string())
+            } else {
+                set.insert(value);
+                None
+            }
+        })?;
+        Ok(set)
+    }
+}
+
+impl<T: ToTokens, H> ToTokens for HashSet<T, H> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut sorted: Vec<_> = self
+            .iter()
+            .map(|value| (value.tokens_to_string(), value))
+            .collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        for (_, value) in sorted {
+            value.to_tokens(tokens);
+        }
+    }
+}
+
+/// Any number of unique `T`, parsed like `Vec<T>`. Duplicates are an error. `ToTokens`
+/// emits the elements in their `Ord` order.
+impl<T: Parse + ToTokens + Ord> Parser for BTreeSet<T> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut set = BTreeSet::<T>::new();
+        parse_unique(tokens, |value| {
+            if set.contains(&value) {
+                Some(value.tokens_to_string())
+            } else {
+                set.insert(value);
+                None
+            }
+        })?;
+        Ok(set)
+    }
+}
+
+impl<T: ToTokens> ToTokens for BTreeSet<T> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for value in self {
+            value.to_tokens(tokens);
+        }
+    }
+}
+
+/// The value in a `HashMap` or `BTreeMap` together with the separator `S` between key and
+/// value. The separator selects the item grammar, see [`ColonValue`] and [`AssignValue`].
+#[derive(Clone, Default)]
+pub struct MapValue<V, S> {
+    /// The separator between key and value
+    pub separator: S,
+    /// The value
+    pub value: V,
+}
+
+impl<V: Parse, S: Parse> Parser for MapValue<V, S> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            separator: S::parser(tokens)?,
+            value: V::parser(tokens)?,
+        })
+    }
+}
+
+impl<V: ToTokens, S: ToTokens> ToTokens for MapValue<V, S> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.separator.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+impl<V, S> std::ops::Deref for MapValue<V, S> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<V, S> std::ops::DerefMut for MapValue<V, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// Creates a `MapValue` with a default constructed separator.
+impl<V, S: Default> From<V> for MapValue<V, S> {
+    fn from(value: V) -> Self {
+        Self {
+            separator: S::default(),
+            value,
+        }
+    }
+}
+
+#[mutants::skip]
+impl<V: std::fmt::Debug, S: std::fmt::Debug> std::fmt::Debug for MapValue<V, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!(
+            "MapValue<{}, {}>",
+            std::any::type_name::<V>(),
+            std::any::type_name::<S>()
+        ))
+        .field("separator", &self.separator)
+        .field("value", &self.value)
+        .finish()
+    }
+}
+
+/// Map value for `key: value` entries
+pub type ColonValue<V> = MapValue<V, Colon>;
+/// Map value for `key = value` entries
+pub type AssignValue<V> = MapValue<V, Assign>;
+
+/// Parses comma delimited `K S V` entries and inserts them with `insert`. Stops after the
+/// first entry without a comma. `insert` returns the string representation of a duplicate key,
+/// this is reported as error at the duplicates position.
+fn parse_map<K: Parse, V: Parse, S: Parse>(
+    tokens: &mut TokenIter,
+    mut insert: impl FnMut(K, MapValue<V, S>) -> Option<String>,
+) -> Result<()> {
+    loop {
+        let at = tokens.clone().next();
+        let Ok(entry) = Delimited::<Cons<K, MapValue<V, S>>, Comma>::parse(tokens) else {
+            return Ok(());
+        };
+        if let Some(duplicate) = insert(entry.value.first, entry.value.second) {
+            return Error::other(at, tokens, format!("duplicate key `{duplicate}`"));
+        }
+        if entry.delimiter.is_none() {
+            return Ok(());
+        }
+    }
+}
+
+/// Emits the entries comma delimited.
+fn map_to_tokens<'a, K: ToTokens + 'a, V: ToTokens + 'a>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+    tokens: &mut TokenStream,
+) {
+    for (n, (key, value)) in entries.into_iter().enumerate() {
+        if n > 0 {
+            Comma::new().to_tokens(tokens);
+        }
+        key.to_tokens(tokens);
+        value.to_tokens(tokens);
+    }
+}
+
+/// Comma delimited `key: value` or `key = value` entries, the item grammar is selected by the
+/// separator of the [`MapValue<V, S>`]. Like [`DelimitedVec`] parsing stops after the first
+/// entry without a trailing comma. Duplicate keys are an error. `ToTokens` emits the entries
+/// ordered by the string representation of their keys.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// # use std::collections::HashMap;
+/// let mut token_iter = "b: 2, a: 1,".to_token_iter();
+/// let map = HashMap::<CachedIdent, ColonValue<LiteralInteger>>::parse(&mut token_iter).unwrap();
+/// assert_eq!(map[&CachedIdent::new("a")].value(), 1);
+/// assert_tokens_eq!(map, "a: 1, b: 2");
+///
+/// let mut token_iter = "a = 1, a = 2".to_token_iter();
+/// assert!(HashMap::<CachedIdent, AssignValue<LiteralInteger>>::parse(&mut token_iter).is_err());
+/// ```
+impl<K, V, S, H> Parser for HashMap<K, MapValue<V, S>, H>
+where
+    K: Parse + ToTokens + Hash + Eq,
+    V: Parse,
+    S: Parse,
+    H: BuildHasher + Default,
+{
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut map = HashMap::<K, MapValue<V, S>, H>::default();
+        parse_map(tokens, |key, value| match map.entry(key) {
+            hash_map::Entry::Occupied(entry) => Some(entry.key().tokens_to_string()),
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        })?;
+        Ok(map)
+    }
+}
+
+impl<K: ToTokens, V: ToTokens, H> ToTokens for HashMap<K, V, H> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut sorted: Vec<_> = self
+            .iter()
+            .map(|entry| (entry.0.tokens_to_string(), entry))
+            .collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        map_to_tokens(sorted.into_iter().map(|(_, entry)| entry), tokens);
+    }
+}
+
+/// Comma delimited `key: value` or `key = value` entries, see `HashMap`. `ToTokens` emits the
+/// entries in the `Ord` order of their keys.
+impl<K: Parse + ToTokens + Ord, V: Parse, S: Parse> Parser for BTreeMap<K, MapValue<V, S>> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut map = BTreeMap::<K, MapValue<V, S>>::new();
+        parse_map(tokens, |key, value| match map.entry(key) {
+            btree_map::Entry::Occupied(entry) => Some(entry.key().tokens_to_string()),
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        })?;
+        Ok(map)
+    }
+}
+
+impl<K: ToTokens, V: ToTokens> ToTokens for BTreeMap<K, V> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        map_to_tokens(self, tokens);
+    }
+}
+
 /// A `Vec<T>` that is filled up to the first appearance of an terminating `S`.  This `S` may
 /// be a subset of `T`, thus parsing become lazy.  This is the same as
 /// `Cons<Vec<Cons<Except<S>,T>>,S>` but more convenient and efficient.
@@ -214,7 +596,7 @@ impl<T: ToTokens, S: ToTokens> ToTokens for LazyVec<T, S> {
 
 impl<T: Parse, S: Parse> RangedRepeats for LazyVec<T, S> {
     fn parse_repeats(tokens: &mut TokenIter, min: usize, max: usize) -> Result<Self> {
-        let mut vec = Vec::with_capacity(min);
+        let mut vec = Vec::with_capacity(min.min(PREALLOCATE_LIMIT));
         let mut at = tokens.clone().next();
         for _ in 0..max {
             at = tokens.clone().next();
@@ -261,6 +643,31 @@ impl<T: std::fmt::Debug, S: std::fmt::Debug> std::fmt::Debug for LazyVec<T, S> {
 /// Since the delimiter in [`Delimited<T,D>`] is optional a [`Vec<Delimited<T,D>>`] would parse
 /// consecutive values even without delimiters. [`DelimitedVec<T,D>`] will stop parsing after
 /// the first value without a delimiter.
+///
+/// Besides the `Vec` it dereferences to, `DelimitedVec` has a vector like API that maintains
+/// the delimiters. Values added with [`push()`](DelimitedVec::push),
+/// [`insert()`](DelimitedVec::insert), `extend()` or `collect()` get default constructed
+/// delimiters where needed. The same API is available for [`Repeats`], there the `MIN`/`MAX`
+/// limits are only enforced by parsing.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "a, b".to_token_iter();
+/// let mut list = CommaDelimitedVec::<Ident>::parse(&mut token_iter).unwrap();
+///
+/// list.push(Ident::new("d", Span::call_site()));
+/// list.insert(2, Ident::new("c", Span::call_site()));
+/// assert_tokens_eq!(list, "a, b, c, d");
+/// assert_eq!(list.delimiters().count(), 3);
+///
+/// list.remove(0);
+/// list.remove(2);
+/// list.push_punct(Comma::new());
+/// assert_tokens_eq!(list, "b, c,");
+/// assert_eq!(list.values().last().unwrap().to_string(), "c");
+/// ```
 #[derive(Clone)]
 pub struct DelimitedVec<T, D>(pub Vec<Delimited<T, D>>);
 
@@ -320,7 +727,7 @@ impl<T: ToTokens, D: ToTokens> ToTokens for DelimitedVec<T, D> {
 
 impl<T: Parse, D: Parse> RangedRepeats for DelimitedVec<T, D> {
     fn parse_repeats(tokens: &mut TokenIter, min: usize, max: usize) -> Result<Self> {
-        let mut output = Vec::with_capacity(min);
+        let mut output = Vec::with_capacity(min.min(PREALLOCATE_LIMIT));
         let mut at = tokens.clone().next();
         for _ in 0..max {
             at = tokens.clone().next();
@@ -377,18 +784,9 @@ impl<T, D> std::ops::Deref for DelimitedVec<T, D> {
 /// ```
 impl<T, D: Default> FromIterator<T> for DelimitedVec<T, D> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut v: Vec<Delimited<T, D>> = Vec::new();
-        for value in iter {
-            if let Some(last) = v.last_mut() {
-                last.delimiter = Some(D::default());
-            }
-            v.push(Delimited {
-                value,
-                delimiter: None,
-            });
-        }
-
-        Self(v)
+        let mut delimited_vec = Self::new();
+        delimited_vec.extend(iter);
+        delimited_vec
     }
 }
 
@@ -427,12 +825,15 @@ impl<const MIN: usize, const MAX: usize, T: Parse, D: Parse> Parser for Repeats<
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
         let mut output = Vec::new();
         let mut at = tokens.clone().next();
-        while let Ok(delimited) = Delimited::<T, D>::parse(tokens) {
+        // check MAX before parsing, `Repeats<0, 0, T>` must not consume anything
+        while output.len() < MAX {
+            let Ok(delimited) = Delimited::<T, D>::parse(tokens) else {
+                break;
+            };
             at = tokens.clone().next();
             let done = delimited.delimiter.is_none();
             output.push(delimited);
-            #[allow(unused_comparisons)]
-            if done || output.len() >= MAX {
+            if done {
                 break;
             }
         }
@@ -512,4 +913,524 @@ pub type AtMost<const N: usize, T, D = Nothing> = Repeats<0, N, T, D>;
 /// At least N of T delimited by D or [`Nothing`]
 pub type AtLeast<const N: usize, T, D = Nothing> = Repeats<N, { usize::MAX }, T, D>;
 
-// PLANNED: needs https://github.com/rust-lang/rust/issues/96097 impl<const N: usize, T: Parse> Parser for [T;N] {
+// Implements the vector like API of `DelimitedVec` and `Repeats`. Values added
+// programmatically get default constructed delimiters inserted where needed, thus `ToTokens`
+// emits correct separators. The `MIN`/`MAX` limits of `Repeats` are only enforced by parsing.
+macro_rules! impl_delimited_vec_api {
+    ($([$($generics:tt)*] $ty:ty;)*) => {
+        $(
+            impl<$($generics)*> $ty {
+                /// Returns an iterator over the values with their delimiters.
+                pub fn iter(&self) -> std::slice::Iter<'_, Delimited<T, D>> {
+                    self.0.iter()
+                }
+
+                /// Returns an iterator over the mutable values with their delimiters.
+                pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Delimited<T, D>> {
+                    self.0.iter_mut()
+                }
+
+                /// Returns an iterator over the values without their delimiters.
+                pub fn values(&self) -> impl Iterator<Item = &T> {
+                    self.0.iter().map(|delimited| &delimited.value)
+                }
+
+                /// Returns an iterator over the mutable values without their delimiters.
+                pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+                    self.0.iter_mut().map(|delimited| &mut delimited.value)
+                }
+
+                /// Returns an iterator over the present delimiters.
+                pub fn delimiters(&self) -> impl Iterator<Item = &D> {
+                    self.0.iter().filter_map(|delimited| delimited.delimiter.as_ref())
+                }
+
+                /// Sets `delimiter` as trailing delimiter of the last value.
+                ///
+                /// # Panics
+                ///
+                /// Panics when there is no value.
+                pub fn push_punct(&mut self, delimiter: D) {
+                    self.0
+                        .last_mut()
+                        .expect("push_punct on empty list")
+                        .delimiter = Some(delimiter);
+                }
+
+                /// Removes and returns the value at `index`. When the last value is removed,
+                /// the new last value takes over its (possibly absent) trailing delimiter.
+                ///
+                /// # Panics
+                ///
+                /// Panics when `index` is out of bounds.
+                pub fn remove(&mut self, index: usize) -> T {
+                    let removed = self.0.remove(index);
+                    if index == self.0.len() {
+                        if let Some(last) = self.0.last_mut() {
+                            last.delimiter = removed.delimiter;
+                        }
+                    }
+                    removed.value
+                }
+            }
+
+            impl<$($generics)*> $ty
+            where
+                D: Default,
+            {
+                /// Appends `value`, a default delimiter is added to the former last value when it
+                /// had none.
+                pub fn push(&mut self, value: T) {
+                    if let Some(last) = self.0.last_mut() {
+                        last.delimiter.get_or_insert_with(D::default);
+                    }
+                    self.0.push(Delimited {
+                        value,
+                        delimiter: None,
+                    });
+                }
+
+                /// Inserts `value` at `index`, it gets a default delimiter when it is followed
+                /// by other values.
+                ///
+                /// # Panics
+                ///
+                /// Panics when `index > len`.
+                pub fn insert(&mut self, index: usize, value: T) {
+                    if index == self.0.len() {
+                        self.push(value);
+                    } else {
+                        self.0.insert(
+                            index,
+                            Delimited {
+                                value,
+                                delimiter: Some(D::default()),
+                            },
+                        );
+                    }
+                }
+            }
+
+            impl<$($generics)*> Extend<T> for $ty
+            where
+                D: Default,
+            {
+                fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                    for value in iter {
+                        self.push(value);
+                    }
+                }
+            }
+
+            impl<'a, $($generics)*> IntoIterator for &'a $ty {
+                type Item = &'a Delimited<T, D>;
+                type IntoIter = std::slice::Iter<'a, Delimited<T, D>>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.0.iter()
+                }
+            }
+
+            impl<'a, $($generics)*> IntoIterator for &'a mut $ty {
+                type Item = &'a mut Delimited<T, D>;
+                type IntoIter = std::slice::IterMut<'a, Delimited<T, D>>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.0.iter_mut()
+                }
+            }
+        )*
+    };
+}
+
+impl_delimited_vec_api! {
+    [T, D] DelimitedVec<T, D>;
+    [const MIN: usize, const MAX: usize, T, D] Repeats<MIN, MAX, T, D>;
+}
+
+/// Creates a `Repeats` from a iterator over T and default constructed delimiters.
+impl<const MIN: usize, const MAX: usize, T, D: Default> FromIterator<T>
+    for Repeats<MIN, MAX, T, D>
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut repeats = Self(Vec::new());
+        repeats.extend(iter);
+        repeats
+    }
+}
+
+/// At least one `T` delimited by `D`. Parses like [`Many<T, D>`] but keeps the guarantee that
+/// there is a first element in the type, thus [`first()`](NonEmptyVec::first) and
+/// [`last()`](NonEmptyVec::last) return `&T` and not `Option<&T>`. This is the vector
+/// counterpart of [`NonEmptyTokenStream`](crate::NonEmptyTokenStream).
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "a, b, c".to_token_iter();
+///
+/// let idents = NonEmptyVec::<Ident, Comma>::parse(&mut token_iter).unwrap();
+/// assert_eq!(idents.first().to_string(), "a");
+/// assert_eq!(idents.last().to_string(), "c");
+/// assert_eq!(idents.len(), 3);
+/// assert_tokens_eq!(idents, "a, b, c");
+///
+/// let mut token_iter = "".to_token_iter();
+/// assert!(NonEmptyVec::<Ident, Comma>::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Clone)]
+pub struct NonEmptyVec<T, D = Nothing>(Vec<Delimited<T, D>>);
+
+impl<T, D> NonEmptyVec<T, D> {
+    /// Returns the first element.
+    #[must_use]
+    pub fn first(&self) -> &T {
+        &self.0[0].value
+    }
+
+    /// Returns the first element mutably.
+    #[must_use]
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0[0].value
+    }
+
+    /// Returns the last element.
+    #[must_use]
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1].value
+    }
+
+    /// Returns the last element mutably.
+    #[must_use]
+    pub fn last_mut(&mut self) -> &mut T {
+        let last = self.0.len() - 1;
+        &mut self.0[last].value
+    }
+
+    /// Returns an iterator over the values without their delimiters.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().map(|delimited| &delimited.value)
+    }
+
+    /// Deconstructs self and returns the inner `Vec`, which is never empty.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<Delimited<T, D>> {
+        self.0
+    }
+}
+
+impl<T: Parse, D: Parse> Parser for NonEmptyVec<T, D> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self(Many::<T, D>::parser(tokens).refine_err::<Self>()?.0))
+    }
+}
+
+impl<T: ToTokens, D: ToTokens> ToTokens for NonEmptyVec<T, D> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+/// Fails with `Error::unexpected_end()` when the `DelimitedVec` is empty.
+impl<T, D> TryFrom<DelimitedVec<T, D>> for NonEmptyVec<T, D> {
+    type Error = Error;
+
+    fn try_from(value: DelimitedVec<T, D>) -> Result<Self> {
+        if value.is_empty() {
+            Error::unexpected_end()
+        } else {
+            Ok(Self(value.0))
+        }
+    }
+}
+
+/// Converts a [`NonEmptyVec<T, D>`] into a [`Vec<T>`].
+/// As with [`DelimitedVec`] this loses the potentially stateful delimiters.
+impl<T, D> From<NonEmptyVec<T, D>> for Vec<T> {
+    fn from(non_empty: NonEmptyVec<T, D>) -> Self {
+        non_empty
+            .0
+            .into_iter()
+            .map(|delimited| delimited.value)
+            .collect()
+    }
+}
+
+impl<T, D> IntoIterator for NonEmptyVec<T, D> {
+    type Item = Delimited<T, D>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, D> IntoIterator for &'a NonEmptyVec<T, D> {
+    type Item = &'a Delimited<T, D>;
+    type IntoIter = std::slice::Iter<'a, Delimited<T, D>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T, D> std::ops::Deref for NonEmptyVec<T, D> {
+    type Target = [Delimited<T, D>];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[mutants::skip]
+impl<T: std::fmt::Debug, D: std::fmt::Debug> std::fmt::Debug for NonEmptyVec<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!(
+            "NonEmptyVec<{}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<D>()
+        ))
+        .field(&self.0)
+        .finish()
+    }
+}
+
+/// Types that can supply the number of repetitions for a [`CountedRepeats`]. This is
+/// implemented for [`LiteralInteger`], the unsigned integer types and for [`Cons`] where the
+/// count is taken from the first element. The later allows counts followed by some
+/// punctuation such as `Cons<LiteralInteger, Colon>`.
+pub trait RepeatCount {
+    /// The number of elements that have to follow.
+    fn repeat_count(&self) -> usize;
+}
+
+impl RepeatCount for LiteralInteger {
+    /// Values that do not fit into a `usize` saturate to `usize::MAX`.
+    fn repeat_count(&self) -> usize {
+        usize::try_from(self.value()).unwrap_or(usize::MAX)
+    }
+}
+
+macro_rules! impl_repeat_count {
+    ($($ty:ty),*) => {
+        $(
+            impl RepeatCount for $ty {
+                fn repeat_count(&self) -> usize {
+                    usize::try_from(*self).unwrap_or(usize::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_repeat_count! {u8, u16, u32, u64, u128, usize}
+
+impl<A: RepeatCount, B, C, D> RepeatCount for Cons<A, B, C, D> {
+    fn repeat_count(&self) -> usize {
+        self.first.repeat_count()
+    }
+}
+
+/// Parses a count `N` first and then exactly that many `T` delimited by `D` or [`Nothing`].
+/// Both, the count and the elements are stored and emitted again by [`ToTokens`]. This is
+/// used for formats where the number of elements is given in the input.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "3: a b c d".to_token_iter();
+///
+/// let counted = CountedRepeats::<Cons<LiteralInteger, Colon>, Ident>::parse(&mut token_iter).unwrap();
+/// assert_eq!(counted.len(), 3);
+/// assert_tokens_eq!(counted, "3: a b c");
+/// # assert_tokens_eq!(token_iter, "d");
+///
+/// // the count may be followed by delimited elements
+/// let mut token_iter = "2 a, b".to_token_iter();
+/// let counted = CountedRepeats::<usize, Ident, Comma>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(counted, "2 a, b");
+///
+/// // less elements than requested is an error
+/// let mut token_iter = "3 a b".to_token_iter();
+/// assert!(CountedRepeats::<usize, Ident>::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Clone)]
+pub struct CountedRepeats<N, T, D = Nothing> {
+    /// The parsed count
+    pub count: N,
+    /// Exactly `count` elements
+    pub items: DelimitedVec<T, D>,
+}
+
+impl<N, T, D> CountedRepeats<N, T, D> {
+    /// Returns the number of parsed elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.0.len()
+    }
+
+    /// Returns `true` when no elements were parsed (the count was zero).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.0.is_empty()
+    }
+}
+
+impl<N: Parse + RepeatCount, T: Parse, D: Parse> Parser for CountedRepeats<N, T, D> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let count = N::parser(tokens)?;
+        let items =
+            <DelimitedVec<T, D> as RangedRepeats>::parse_exactly(tokens, count.repeat_count())
+                .refine_err::<Self>()?;
+        Ok(Self { count, items })
+    }
+}
+
+impl<N: ToTokens, T: ToTokens, D: ToTokens> ToTokens for CountedRepeats<N, T, D> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.count.to_tokens(tokens);
+        self.items.to_tokens(tokens);
+    }
+}
+
+/// Converts a [`CountedRepeats<N, T, D>`] into a [`Vec<T>`].
+/// This loses the count and the potentially stateful delimiters.
+impl<N, T, D> From<CountedRepeats<N, T, D>> for Vec<T> {
+    fn from(counted: CountedRepeats<N, T, D>) -> Self {
+        counted.items.into()
+    }
+}
+
+impl<N, T, D> IntoIterator for CountedRepeats<N, T, D> {
+    type Item = Delimited<T, D>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[mutants::skip]
+impl<N: std::fmt::Debug, T: std::fmt::Debug, D: std::fmt::Debug> std::fmt::Debug
+    for CountedRepeats<N, T, D>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!(
+            "CountedRepeats<{}, {}, {}>",
+            std::any::type_name::<N>(),
+            std::any::type_name::<T>(),
+            std::any::type_name::<D>()
+        ))
+        .field("count", &self.count)
+        .field("items", &self.items)
+        .finish()
+    }
+}
+
+/// Converts the elements of a successfully parsed [`Exactly<N, T, D>`] into an array.
+///
+/// `Exactly` only succeeds with `N` elements, thus the conversion can not fail.
+fn into_array<const N: usize, T>(vec: Vec<T>) -> [T; N] {
+    vec.try_into()
+        .unwrap_or_else(|vec: Vec<T>| unreachable!("Exactly<{N}> parsed {} elements", vec.len()))
+}
+
+/// Exactly `N` of `T`, same as [`Exactly<N, T>`] but into a array.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "255 128 0 1".to_token_iter();
+///
+/// let rgb = <[u8; 3]>::parse(&mut token_iter).unwrap();
+/// assert_eq!(rgb, [255, 128, 0]);
+/// assert_tokens_eq!(rgb, "255 128 0");
+/// ```
+impl<const N: usize, T: Parse> Parser for [T; N] {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let exactly = Exactly::<N, T>::parser(tokens).refine_err::<Self>()?;
+        Ok(into_array(exactly.into()))
+    }
+}
+
+impl<const N: usize, T: ToTokens> ToTokens for [T; N] {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_slice().to_tokens(tokens);
+    }
+}
+
+/// Exactly `N` of `T` delimited by `D`, the array counterpart of [`Exactly<N, T, D>`]. As there
+/// the delimiter after the last element is optional.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "1, 0, 0, 0, 2".to_token_iter();
+///
+/// let row = DelimitedArray::<4, LiteralInteger, Comma>::parse(&mut token_iter).unwrap();
+/// assert_eq!(row[3].value.value(), 0);
+/// assert_tokens_eq!(row, "1, 0, 0, 0,");
+///
+/// let values: [LiteralInteger; 4] = row.into();
+/// assert_eq!(values[0].value(), 1);
+/// ```
+#[derive(Clone)]
+pub struct DelimitedArray<const N: usize, T, D>(pub [Delimited<T, D>; N]);
+
+impl<const N: usize, T: Parse, D: Parse> Parser for DelimitedArray<N, T, D> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let exactly = Exactly::<N, T, D>::parser(tokens).refine_err::<Self>()?;
+        Ok(Self(into_array(exactly.0)))
+    }
+}
+
+impl<const N: usize, T: ToTokens, D: ToTokens> ToTokens for DelimitedArray<N, T, D> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+/// Converts a [`DelimitedArray<N, T, D>`] into a `[T; N]`.
+/// As with [`DelimitedVec`] this loses the potentially stateful delimiters.
+impl<const N: usize, T, D> From<DelimitedArray<N, T, D>> for [T; N] {
+    fn from(array: DelimitedArray<N, T, D>) -> Self {
+        array.0.map(|delimited| delimited.value)
+    }
+}
+
+impl<const N: usize, T, D> IntoIterator for DelimitedArray<N, T, D> {
+    type Item = Delimited<T, D>;
+    type IntoIter = std::array::IntoIter<Self::Item, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<const N: usize, T, D> std::ops::Deref for DelimitedArray<N, T, D> {
+    type Target = [Delimited<T, D>; N];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[mutants::skip]
+impl<const N: usize, T: std::fmt::Debug, D: std::fmt::Debug> std::fmt::Debug
+    for DelimitedArray<N, T, D>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!(
+            "DelimitedArray<{N}, {}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<D>()
+        ))
+        .field(&self.0)
+        .finish()
+    }
+}
+
+/// Array of `N` `T` delimited by `,`
+pub type CommaDelimitedArray<const N: usize, T> = DelimitedArray<N, T, Comma>;
@@ -73,6 +73,13 @@ impl Error {
         self.pos = pos.token_count();
     }
 
+    /// Moves the error to `after`. Used when a value was checked on a copy of its tokens and
+    /// the error should refer to the place the value was originally parsed from.
+    pub(crate) fn relocate(&mut self, after: &TokenIter) {
+        self.after = Some(after.clone().into_inner_iter());
+        self.pos = after.token_count();
+    }
+
     /// Get the position of the error.
     #[must_use]
     pub const fn pos(&self) -> usize {
@@ -159,7 +166,7 @@ impl Error {
     /// Returns a iterator to the tokens after the error
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
-    pub fn tokens_after(&self) -> TokenIter {
+    pub fn tokens_after(&self) -> TokenIter<'_> {
         let mut tokens =
             TokenIter::new(self.after.clone().unwrap_or(TokenStream::new().into_iter()));
         tokens.add((self.pos + 1).try_into().expect("to many tokens"));
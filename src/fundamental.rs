@@ -367,6 +367,8 @@ gen_cached_types! {
     CachedLiteral = Literal;
     CachedLiteralString = LiteralString;
     CachedLiteralInteger = LiteralInteger;
+    CachedLifetime = Lifetime;
+    CachedLabel = Label;
 }
 
 // cant use the macro, TokenTree conversion is generic over T defined above
@@ -0,0 +1,524 @@
+//! Visibility, generic parameters and where clauses as used by derive style macros.
+//!
+//! [`Generics::split_for_impl()`] produces the token streams needed to implement a trait for
+//! a parsed type, they can be interpolated with [`quote!`].
+#![allow(clippy::module_name_repetitions)]
+
+#[allow(clippy::wildcard_imports)]
+use crate::*;
+
+use crate::path::rust_keyword;
+
+rust_keyword! {
+    Pub = "pub";
+    In = "in";
+    Where = "where";
+    For = "for";
+}
+
+/// The visibility of an item or field. This always parses, a missing visibility is
+/// [`Visibility::Inherited`].
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "pub(crate) pub(in a::b) pub (u8,)".to_token_iter();
+///
+/// let crate_vis = Visibility::parse(&mut token_iter).unwrap();
+/// assert!(matches!(crate_vis, Visibility::Restricted { .. }));
+/// let in_vis = Visibility::parse(&mut token_iter).unwrap();
+/// assert!(matches!(in_vis, Visibility::RestrictedIn { .. }));
+/// // `(u8,)` is not a restriction, it is left for a tuple field type
+/// let public = Visibility::parse(&mut token_iter).unwrap();
+/// assert!(matches!(public, Visibility::Public(_)));
+/// assert!(Visibility::parse(&mut token_iter).unwrap().is_inherited());
+/// # assert_tokens_eq!(token_iter, "(u8,)");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub enum Visibility {
+    /// `pub(crate)`, `pub(self)` or `pub(super)`
+    Restricted {
+        /// The `pub`
+        pub_kw: Pub,
+        /// `crate`, `self` or `super`
+        scope: ParenthesisGroupContaining<CachedIdent>,
+    },
+    /// `pub(in path)`
+    RestrictedIn {
+        /// The `pub`
+        pub_kw: Pub,
+        /// `in path`
+        scope: ParenthesisGroupContaining<Cons<In, SimplePath>>,
+    },
+    /// `pub`
+    Public(Pub),
+    /// No visibility given
+    #[default]
+    Inherited,
+}
+
+impl Visibility {
+    /// Returns `true` when no visibility was given.
+    #[must_use]
+    pub const fn is_inherited(&self) -> bool {
+        matches!(self, Self::Inherited)
+    }
+}
+
+impl Parser for Visibility {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        if let Ok(restricted) = tokens.transaction(|tokens| {
+            let at = tokens.clone().next();
+            let pub_kw = Pub::parser(tokens)?;
+            let scope = ParenthesisGroupContaining::<CachedIdent>::parser(tokens)?;
+            if matches!(scope.content.as_str(), "crate" | "self" | "super") {
+                Ok(Self::Restricted { pub_kw, scope })
+            } else {
+                Error::unexpected_token(at, tokens)
+            }
+        }) {
+            return Ok(restricted);
+        }
+        if let Ok(restricted_in) = tokens.transaction(|tokens| {
+            Ok(Self::RestrictedIn {
+                pub_kw: Pub::parser(tokens)?,
+                scope: ParenthesisGroupContaining::parser(tokens)?,
+            })
+        }) {
+            return Ok(restricted_in);
+        }
+        Ok(Pub::parse(tokens).map_or(Self::Inherited, Self::Public))
+    }
+}
+
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Restricted { pub_kw, scope } => {
+                pub_kw.to_tokens(tokens);
+                scope.to_tokens(tokens);
+            }
+            Self::RestrictedIn { pub_kw, scope } => {
+                pub_kw.to_tokens(tokens);
+                scope.to_tokens(tokens);
+            }
+            Self::Public(pub_kw) => pub_kw.to_tokens(tokens),
+            Self::Inherited => {}
+        }
+    }
+}
+
+/// Higher-ranked lifetimes of a trait bound, `for<'a, 'b>`.
+pub type ForLifetimes = Cons<For, Lt, CommaDelimitedVec<Lifetime>, Gt>;
+
+/// The parenthesized arguments and optional return type of `Fn(A, B) -> R` style bounds.
+pub type FnArgs =
+    Cons<ParenthesisGroupContaining<CommaDelimitedVec<TypeExpr>>, Option<Cons<RArrow, TypeExpr>>>;
+
+/// A bound on a type, a lifetime `'a` or a trait `Clone`, `?Sized`, `for<'a> Trait<'a>`,
+/// `Fn(u8) -> u8`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "for<'a> Fn(&'a str) -> bool".to_token_iter();
+///
+/// let TypeBound::Trait { for_lifetimes, path, fn_args, .. } =
+///     TypeBound::parse_all(&mut token_iter).unwrap() else { panic!() };
+/// assert_eq!(for_lifetimes.unwrap().third.len(), 1);
+/// assert_eq!(path.last_segment().unwrap().ident, "Fn");
+/// assert_tokens_eq!(fn_args.unwrap().second, "-> bool");
+/// ```
+#[derive(Debug, Clone)]
+pub enum TypeBound {
+    /// A lifetime bound
+    Lifetime(Lifetime),
+    /// A trait bound
+    Trait {
+        /// The `?` of `?Sized`
+        maybe: Option<Question>,
+        /// The higher-ranked lifetimes
+        for_lifetimes: Option<ForLifetimes>,
+        /// The trait
+        path: GenericPath,
+        /// The arguments of `Fn`, `FnMut` and `FnOnce` bounds
+        fn_args: Option<Box<FnArgs>>,
+    },
+}
+
+impl Parser for TypeBound {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(lifetime) = err.upgrade(Lifetime::parse(tokens)) {
+            return Ok(Self::Lifetime(lifetime));
+        }
+        if let Ok(bound) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Trait {
+                maybe: Option::<Question>::parser(tokens)?,
+                for_lifetimes: Option::<ForLifetimes>::parser(tokens)?,
+                path: GenericPath::parser(tokens)?,
+                fn_args: Option::<Box<FnArgs>>::parser(tokens)?,
+            })
+        })) {
+            return Ok(bound);
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for TypeBound {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime(lifetime) => lifetime.to_tokens(tokens),
+            Self::Trait {
+                maybe,
+                for_lifetimes,
+                path,
+                fn_args,
+            } => {
+                maybe.to_tokens(tokens);
+                for_lifetimes.to_tokens(tokens);
+                path.to_tokens(tokens);
+                fn_args.to_tokens(tokens);
+            }
+        }
+    }
+}
+
+/// `+` delimited bounds.
+pub type Bounds<T> = DelimitedVec<T, Plus>;
+
+/// A single generic parameter.
+#[derive(Debug, Clone)]
+pub enum GenericParam {
+    /// `'a: 'b + 'c`
+    Lifetime {
+        /// The lifetime
+        lifetime: Lifetime,
+        /// The optional bounds
+        bounds: Option<Cons<Colon, Bounds<Lifetime>>>,
+    },
+    /// `const N: usize = 1`
+    Const {
+        /// The `const`
+        const_kw: Const,
+        /// The name
+        ident: CachedIdent,
+        /// The `:`
+        colon: Colon,
+        /// The type
+        ty: Box<TypeExpr>,
+        /// The optional default
+        default: Option<Cons<Assign, Box<GenericArg>>>,
+    },
+    /// `T: Bound = Default`
+    Type {
+        /// The name
+        ident: CachedIdent,
+        /// The optional bounds
+        bounds: Option<Cons<Colon, Bounds<TypeBound>>>,
+        /// The optional default
+        default: Option<Cons<Assign, TypeExpr>>,
+    },
+}
+
+impl GenericParam {
+    /// Gets the name of the parameter, for lifetimes without the tick.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Lifetime { lifetime, .. } => lifetime.name(),
+            Self::Const { ident, .. } | Self::Type { ident, .. } => ident.as_str(),
+        }
+    }
+
+    /// Emits the parameter as used in `impl<...>`, without defaults.
+    fn to_impl_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime { lifetime, bounds } => {
+                lifetime.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+            Self::Const {
+                const_kw,
+                ident,
+                colon,
+                ty,
+                ..
+            } => {
+                const_kw.to_tokens(tokens);
+                ident.to_tokens(tokens);
+                colon.to_tokens(tokens);
+                ty.to_tokens(tokens);
+            }
+            Self::Type { ident, bounds, .. } => {
+                ident.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+        }
+    }
+
+    /// Emits the parameter as used in `Type<...>`, only the name.
+    fn to_type_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime { lifetime, .. } => lifetime.to_tokens(tokens),
+            Self::Const { ident, .. } | Self::Type { ident, .. } => ident.to_tokens(tokens),
+        }
+    }
+}
+
+impl Parser for GenericParam {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(lifetime) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Lifetime {
+                lifetime: Lifetime::parser(tokens)?,
+                bounds: Option::<Cons<Colon, Bounds<Lifetime>>>::parser(tokens)?,
+            })
+        })) {
+            return Ok(lifetime);
+        }
+        if let Ok(constant) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Const {
+                const_kw: Const::parser(tokens)?,
+                ident: CachedIdent::parser(tokens)?,
+                colon: Colon::parser(tokens)?,
+                ty: Box::parser(tokens)?,
+                default: Option::<Cons<Assign, Box<GenericArg>>>::parser(tokens)?,
+            })
+        })) {
+            return Ok(constant);
+        }
+        if let Ok(ty) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Type {
+                ident: CachedIdent::parser(tokens)?,
+                bounds: Option::<Cons<Colon, Bounds<TypeBound>>>::parser(tokens)?,
+                default: Option::<Cons<Assign, TypeExpr>>::parser(tokens)?,
+            })
+        })) {
+            return Ok(ty);
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for GenericParam {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime { .. } => self.to_impl_tokens(tokens),
+            Self::Const { default, .. } => {
+                self.to_impl_tokens(tokens);
+                default.to_tokens(tokens);
+            }
+            Self::Type { default, .. } => {
+                self.to_impl_tokens(tokens);
+                default.to_tokens(tokens);
+            }
+        }
+    }
+}
+
+/// Generic parameters `<'a, T: Bound, const N: usize>`. This always parses, when there is
+/// no `<` the generics are empty.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "Foo<'a, T: Clone + 'a = u8, const N: usize> where T: Send".to_token_iter();
+///
+/// let _name = Ident::parse(&mut token_iter).unwrap();
+/// let generics = Generics::parse(&mut token_iter).unwrap();
+/// let where_clause = Option::<WhereClause>::parse(&mut token_iter).unwrap();
+///
+/// let (impl_generics, type_generics, where_clause) =
+///     generics.split_for_impl(where_clause.as_ref());
+/// assert_tokens_eq!(impl_generics, "< 'a, T: Clone + 'a, const N: usize>");
+/// assert_tokens_eq!(type_generics, "< 'a, T, N>");
+/// assert_tokens_eq!(where_clause, "where T: Send");
+///
+/// let implementation = quote! {
+///     impl #impl_generics Trait for Foo #type_generics #where_clause {}
+/// };
+/// assert_tokens_eq!(
+///     implementation,
+///     "impl< 'a, T: Clone + 'a, const N: usize> Trait for Foo< 'a, T, N> where T: Send {}"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Generics {
+    /// The `<`, `None` when there are no generics
+    pub lt: Option<Lt>,
+    /// The parameters
+    pub params: CommaDelimitedVec<GenericParam>,
+    /// The `>`, `None` when there are no generics
+    pub gt: Option<Gt>,
+}
+
+impl Generics {
+    /// Returns `true` when there are no generic parameters.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Splits the generics into the tokens needed to implement a trait for the generic type.
+    /// Returns the parameters for `impl<...>` with bounds but without defaults, the arguments
+    /// for `Type<...>` and the `where_clause` tokens. Empty generics and a missing where
+    /// clause produce empty token streams.
+    #[must_use]
+    pub fn split_for_impl(
+        &self,
+        where_clause: Option<&WhereClause>,
+    ) -> (TokenStream, TokenStream, TokenStream) {
+        let mut impl_generics = TokenStream::new();
+        let mut type_generics = TokenStream::new();
+        if !self.is_empty() {
+            Lt::new().to_tokens(&mut impl_generics);
+            Lt::new().to_tokens(&mut type_generics);
+            for (i, param) in self.params.values().enumerate() {
+                if i > 0 {
+                    Comma::new().to_tokens(&mut impl_generics);
+                    Comma::new().to_tokens(&mut type_generics);
+                }
+                param.to_impl_tokens(&mut impl_generics);
+                param.to_type_tokens(&mut type_generics);
+            }
+            Gt::new().to_tokens(&mut impl_generics);
+            Gt::new().to_tokens(&mut type_generics);
+        }
+        (
+            impl_generics,
+            type_generics,
+            where_clause
+                .map(ToTokens::to_token_stream)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl Parser for Generics {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let Some(lt) = Option::<Lt>::parser(tokens).refine_err::<Self>()? else {
+            return Ok(Self::default());
+        };
+        Ok(Self {
+            lt: Some(lt),
+            params: CommaDelimitedVec::parser(tokens).refine_err::<Self>()?,
+            gt: Some(Gt::parser(tokens).refine_err::<Self>()?),
+        })
+    }
+}
+
+impl ToTokens for Generics {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.lt.to_tokens(tokens);
+        self.params.to_tokens(tokens);
+        self.gt.to_tokens(tokens);
+    }
+}
+
+/// A single predicate in a where clause.
+#[derive(Debug, Clone)]
+pub enum WherePredicate {
+    /// `'a: 'b`
+    Lifetime {
+        /// The bounded lifetime
+        lifetime: Lifetime,
+        /// The `:`
+        colon: Colon,
+        /// The bounds
+        bounds: Bounds<Lifetime>,
+    },
+    /// `T: Clone` or `for<'a> F: Fn(&'a u8)`
+    Type {
+        /// The higher-ranked lifetimes
+        for_lifetimes: Option<ForLifetimes>,
+        /// The bounded type
+        ty: TypeExpr,
+        /// The `:`
+        colon: Colon,
+        /// The bounds
+        bounds: Bounds<TypeBound>,
+    },
+}
+
+impl Parser for WherePredicate {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(lifetime) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Lifetime {
+                lifetime: Lifetime::parser(tokens)?,
+                colon: Colon::parser(tokens)?,
+                bounds: Bounds::parser(tokens)?,
+            })
+        })) {
+            return Ok(lifetime);
+        }
+        if let Ok(ty) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Type {
+                for_lifetimes: Option::<ForLifetimes>::parser(tokens)?,
+                ty: TypeExpr::parser(tokens)?,
+                colon: Colon::parser(tokens)?,
+                bounds: Bounds::parser(tokens)?,
+            })
+        })) {
+            return Ok(ty);
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for WherePredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime {
+                lifetime,
+                colon,
+                bounds,
+            } => {
+                lifetime.to_tokens(tokens);
+                colon.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+            Self::Type {
+                for_lifetimes,
+                ty,
+                colon,
+                bounds,
+            } => {
+                for_lifetimes.to_tokens(tokens);
+                ty.to_tokens(tokens);
+                colon.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+        }
+    }
+}
+
+/// A where clause, `where T: Clone, 'a: 'b`.
+#[derive(Debug, Clone)]
+pub struct WhereClause {
+    /// The `where`
+    pub where_kw: Where,
+    /// The predicates
+    pub predicates: CommaDelimitedVec<WherePredicate>,
+}
+
+impl Parser for WhereClause {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            where_kw: Where::parser(tokens).refine_err::<Self>()?,
+            predicates: CommaDelimitedVec::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl ToTokens for WhereClause {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.where_kw.to_tokens(tokens);
+        self.predicates.to_tokens(tokens);
+    }
+}
@@ -1,4 +1,4 @@
-//! Helpers for implementing keyword grouping
+//! Helpers for implementing keyword and operator grouping
 
 use crate::{IParse, Ident, ToTokens};
 
@@ -132,6 +132,20 @@ pub fn create_matchfn(group: &'static KeywordGroup) -> Box<dyn Fn(&str) -> bool
     }
 }
 
+/// Operator groups are matched by trying the operators longest first. The list is lazily
+/// constructed from the static group tree, the entries are validated at compile time by the
+/// `operator!` macro.
+#[doc(hidden)]
+#[must_use]
+#[mutants::skip]
+pub fn create_operator_list(group: &'static KeywordGroup) -> Vec<&'static str> {
+    let mut vec = group.iter().collect::<Vec<_>>();
+    vec.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    // operator groups may contain duplicates
+    vec.dedup();
+    vec
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
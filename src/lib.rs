@@ -22,6 +22,7 @@ pub mod CHANGELOG {
 }
 
 use shadow_counted::ShadowCountedIter;
+use std::marker::PhantomData;
 
 /// Type alias for the iterator type we use for parsing. This Iterator is Clone and produces
 /// `&TokenTree`. The shadow counter counts tokens in the background to track progress which
@@ -167,7 +168,7 @@ pub trait ToTokens {
     fn to_tokens(&self, tokens: &mut TokenStream);
 
     /// Convert `&self` into a [`TokenIter`] object.
-    fn to_token_iter(&self) -> TokenIter {
+    fn to_token_iter(&self) -> TokenIter<'_> {
         self.to_token_stream().into_iter().into()
     }
 
@@ -256,6 +257,18 @@ pub trait IParse: private::Sealed {
     /// Parse a value from the iterator. This is a convenience method that calls
     /// [`Parse::parse_all()`].
     fn parse_all<T: Parse>(self) -> Result<T>;
+
+    /// Returns a iterator that lazily parses consecutive `T` until the end of the stream.
+    /// See [`ParseIter`].
+    fn parse_iter<T: Parse>(self) -> ParseIter<Self, T>
+    where
+        Self: Sized;
+
+    /// Returns a iterator that lazily parses consecutive `T` delimited by `D` until the end of
+    /// the stream. A trailing delimiter is permitted. See [`ParseIter`].
+    fn parse_iter_delimited<T: Parse, D: Parse>(self) -> ParseIter<Self, T, D>
+    where
+        Self: Sized;
 }
 
 impl private::Sealed for &mut TokenIter<'_> {}
@@ -294,6 +307,131 @@ impl IParse for &mut TokenIter<'_> {
     fn parse_all<T: Parse>(self) -> Result<T> {
         T::parse_all(self)
     }
+
+    #[inline]
+    fn parse_iter<T: Parse>(self) -> ParseIter<Self, T> {
+        ParseIter::new(self)
+    }
+
+    #[inline]
+    fn parse_iter_delimited<T: Parse, D: Parse>(self) -> ParseIter<Self, T, D> {
+        ParseIter::new(self)
+    }
+}
+
+/// Iterator that parses `T` on demand, created by [`IParse::parse_iter()`] and
+/// [`IParse::parse_iter_delimited()`]. Items are separated by `D`, which defaults to
+/// [`Nothing`]. Iteration stops cleanly at the end of the stream. The first error is returned
+/// as item, then iteration stops. The tokens of a failed item, including its leading
+/// delimiter, are not consumed. An item that parses without consuming any tokens (such as
+/// `Option<T>` or [`Nothing`]) is an error as well, as it would never end the iteration.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "a, b, c, 1".to_token_iter();
+///
+/// let mut idents = Vec::new();
+/// for ident in token_iter.parse_iter_delimited::<Ident, Comma>() {
+///     match ident {
+///         Ok(ident) => idents.push(ident),
+///         Err(_) => break,
+///     }
+/// }
+/// assert_eq!(idents.len(), 3);
+/// assert_tokens_eq!(token_iter, ", 1");
+///
+/// let mut token_iter = "1 2 3".to_token_iter();
+/// let sum: u32 = token_iter
+///     .parse_iter::<u32>()
+///     .sum::<Result<u32>>()
+///     .unwrap();
+/// assert_eq!(sum, 6);
+/// ```
+pub struct ParseIter<I, T, D = Nothing> {
+    tokens: I,
+    state: ParseIterState,
+    _marker: PhantomData<(T, D)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParseIterState {
+    First,
+    Delimiter,
+    Done,
+}
+
+impl<I, T, D> ParseIter<I, T, D> {
+    const fn new(tokens: I) -> Self {
+        Self {
+            tokens,
+            state: ParseIterState::First,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Parse, D: Parse> Iterator for ParseIter<&mut TokenIter<'_>, T, D> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == ParseIterState::Done || EndOfStream::parse(self.tokens).is_ok() {
+            self.state = ParseIterState::Done;
+            return None;
+        }
+        let result = self.tokens.transaction(|tokens| {
+            let start = tokens.counter();
+            if self.state == ParseIterState::Delimiter {
+                D::parser(tokens)?;
+                // trailing delimiter
+                if EndOfStream::parse(tokens).is_ok() {
+                    return Ok(None);
+                }
+            }
+            let value = T::parser(tokens)?;
+            // a `T` that matches without consuming tokens would be returned forever
+            if tokens.counter() == start {
+                return Error::other(
+                    tokens.clone().next(),
+                    tokens,
+                    format!(
+                        "{} parsed without consuming tokens",
+                        std::any::type_name::<T>()
+                    ),
+                );
+            }
+            Ok(Some(value))
+        });
+        match result {
+            Ok(Some(value)) => {
+                self.state = ParseIterState::Delimiter;
+                Some(Ok(value))
+            }
+            Ok(None) => {
+                self.state = ParseIterState::Done;
+                None
+            }
+            Err(err) => {
+                self.state = ParseIterState::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<I, T, D> std::iter::FusedIterator for ParseIter<I, T, D> where Self: Iterator {}
+
+#[mutants::skip]
+impl<I, T, D> std::fmt::Debug for ParseIter<I, T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!(
+            "ParseIter<{}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<D>()
+        ))
+        .finish_non_exhaustive()
+    }
 }
 
 /// Helper trait to make [`TokenIter`] transactional
@@ -350,6 +488,26 @@ pub mod literal;
 #[doc(inline)]
 pub use literal::*;
 
+// Lifetimes and labels
+pub mod lifetime;
+#[doc(inline)]
+pub use lifetime::*;
+
+// Attributes and doc comments
+pub mod attribute;
+#[doc(inline)]
+pub use attribute::*;
+
+// Paths and type expressions
+pub mod path;
+#[doc(inline)]
+pub use path::*;
+
+// Visibility, generics and where clauses
+pub mod generics;
+#[doc(inline)]
+pub use generics::*;
+
 // Parse into certain rust types
 pub mod rust_types;
 #[doc(inline)]
@@ -378,6 +536,16 @@ pub mod transform;
 #[doc(inline)]
 pub use transform::*;
 
+// validating predicates
+pub mod predicate;
+#[doc(inline)]
+pub use predicate::*;
+
+// meta argument lists
+pub mod meta;
+#[doc(inline)]
+pub use meta::*;
+
 // helpers for the keyword macro
 #[doc(hidden)]
 pub mod keyword_group;
@@ -0,0 +1,149 @@
+//! Lifetimes (`'a`) and loop labels (`'outer:`).
+//!
+//! Both are generic over the parser for the name after the tick. It defaults to
+//! [`CachedIdent`] which matches any name, a [`keyword!`] definition restricts the names, e.g.
+//! to match only `'static`.
+#![allow(clippy::module_name_repetitions)]
+
+#[cfg(doc)]
+use crate::keyword;
+
+use crate::{
+    CachedIdent, Colon, LifetimeTick, Parse, Parser, RefineErr, Result, ToTokens, TokenIter,
+    TokenStream,
+};
+
+/// A lifetime, a joint tick followed by a name, like `'a` or `'static`. The name is parsed
+/// by `K`, which defaults to [`CachedIdent`].
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// keyword! {
+///     Static = "static";
+/// }
+///
+/// let mut token_iter = "'a 'static 'b".to_token_iter();
+///
+/// let lifetime = <Lifetime>::parse(&mut token_iter).unwrap();
+/// assert_eq!(lifetime.name(), "a");
+/// assert_tokens_eq!(lifetime, "'a");
+///
+/// let static_lifetime = Lifetime::<Static>::parse(&mut token_iter).unwrap();
+/// assert_eq!(static_lifetime.name(), "static");
+/// assert!(Lifetime::<Static>::parse(&mut token_iter).is_err());
+///
+/// assert_tokens_eq!(Lifetime::<Static>::default(), "'static");
+/// assert_tokens_eq!(Lifetime::new("c"), "'c");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Lifetime<K = CachedIdent> {
+    /// The joint `'`
+    pub tick: LifetimeTick,
+    /// The name after the tick
+    pub ident: K,
+}
+
+impl Lifetime {
+    /// Creates a new `Lifetime` from its name without the tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `name` is not a valid identifier.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            tick: LifetimeTick::new(),
+            ident: CachedIdent::new(name),
+        }
+    }
+}
+
+impl<K: AsRef<str>> Lifetime<K> {
+    /// Gets the name without the tick.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.ident.as_ref()
+    }
+}
+
+impl<K: Parse> Parser for Lifetime<K> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            tick: LifetimeTick::parser(tokens).refine_err::<Self>()?,
+            ident: K::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl<K: ToTokens> ToTokens for Lifetime<K> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.tick.to_tokens(tokens);
+        self.ident.to_tokens(tokens);
+    }
+}
+
+/// A loop or block label, a [`Lifetime`] followed by a colon, like `'outer:`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "'outer: loop {} 'a".to_token_iter();
+///
+/// let label = <Label>::parse(&mut token_iter).unwrap();
+/// assert_eq!(label.name(), "outer");
+/// assert_tokens_eq!(label, "'outer:");
+/// # let _ = Ident::parse(&mut token_iter).unwrap();
+/// # let _ = BraceGroup::parse(&mut token_iter).unwrap();
+///
+/// // a lifetime without colon is not a label
+/// assert!(<Label>::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Label<K = CachedIdent> {
+    /// The lifetime naming the label
+    pub lifetime: Lifetime<K>,
+    /// The `:` after the lifetime
+    pub colon: Colon,
+}
+
+impl Label {
+    /// Creates a new `Label` from its name without tick and colon.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `name` is not a valid identifier.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            lifetime: Lifetime::new(name),
+            colon: Colon::new(),
+        }
+    }
+}
+
+impl<K: AsRef<str>> Label<K> {
+    /// Gets the name without tick and colon.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.lifetime.name()
+    }
+}
+
+impl<K: Parse> Parser for Label<K> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            lifetime: Lifetime::parser(tokens).refine_err::<Self>()?,
+            colon: Colon::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl<K: ToTokens> ToTokens for Label<K> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.lifetime.to_tokens(tokens);
+        self.colon.to_tokens(tokens);
+    }
+}
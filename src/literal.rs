@@ -1,12 +1,15 @@
 //! This module provides a set of literal types that can be used to parse and tokenize
 //! literals.  The literals are parsed from the token stream and can be used to represent the
-//! parsed value. unsynn defines only simplified literals, such as integers, characters and
-//! strings. The literals here are not full rust syntax, which will be defined in the
-//! `unsynn-rust` crate. There are `Literal*` for `Integer, Character, String` to parse simple
-//! literals and `ConstInteger<V>` and `ConstCharacter<V>` who must match an exact character.
-//! The later two also implement `Default`, thus they can be used to create constant tokens.
-//! There are is no `ConstString`, constant literal strings can be constructed with
-//! [`IntoLiteralString<T>`].
+//! parsed value. unsynn defines only simplified literals, such as integers, floats, characters
+//! and strings. The literals here are not full rust syntax, which will be defined in the
+//! `unsynn-rust` crate. There are `Literal*` for `Integer, Float, Character, String` to parse
+//! simple literals, `LiteralByte`, `LiteralByteString` and `LiteralCString` for the byte and C
+//! string forms, `LiteralSignedInteger` for integers with a sign and `ConstInteger<V>`,
+//! `ConstSignedInteger<V>` and `ConstCharacter<V>` who must match an exact value.
+//! The `Const*` types also implement `Default`, thus they can be used to create constant tokens.
+//! [`LiteralKind`] parses any of these literals and tells which kind it is.
+//! Types matching exact string, float, bool and other literal constants are defined with the
+//! [`literal!`] macro.
 
 #![allow(clippy::module_name_repetitions)]
 
@@ -14,26 +17,53 @@
 use crate::*;
 
 use crate::{
-    Error, Literal, Parse, Parser, RefineErr, Result, ToTokens, TokenIter, TokenStream, TokenTree,
+    Cons, Either, Error, Literal, Minus, Parse, Parser, Plus, RefineErr, Result, ToTokens,
+    TokenIter, TokenStream, TokenTree,
 };
 
-/// A simple unsigned 128 bit integer. This is the most simple form to parse integers. Note
-/// that only decimal integers without any other characters, signs or suffixes are supported,
-/// this is *not* full rust syntax.
+use std::ffi::{CStr, CString};
+
+/// A unsigned 128 bit integer literal. Hexadecimal (`0xff`), octal (`0o77`) and binary
+/// (`0b1010`) radix prefixes, `_` digit separators and integer type suffixes (`42u8`) are
+/// supported. Signs are separate tokens and not part of the literal.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "1_000 0xFF 0b1010u8".to_token_iter();
+///
+/// assert_eq!(LiteralInteger::parse(&mut token_iter).unwrap().value(), 1000);
+/// assert_eq!(LiteralInteger::parse(&mut token_iter).unwrap().value(), 255);
+/// let binary = LiteralInteger::parse(&mut token_iter).unwrap();
+/// assert_eq!(binary.value(), 10);
+/// assert_eq!(binary.suffix(), "u8");
+/// ```
 #[derive(Debug, Clone)]
 pub struct LiteralInteger {
     /// Literal representing an integer
     literal: Literal,
     /// Value of the integer
     value: u128,
+    /// The type suffix, empty when there is none
+    suffix: &'static str,
 }
 
+/// The suffixes integer literals may have.
+const INTEGER_SUFFIXES: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
 impl LiteralInteger {
     /// Create a new `LiteralInteger` from a `u128` value.
     #[must_use]
     pub fn new(value: u128) -> Self {
         let literal = Literal::u128_unsuffixed(value);
-        Self { literal, value }
+        Self {
+            literal,
+            value,
+            suffix: "",
+        }
     }
 
     /// Get the value.
@@ -42,12 +72,15 @@ impl LiteralInteger {
         self.value
     }
 
+    /// Get the type suffix such as `"u8"`, empty when there is none.
+    #[must_use]
+    pub const fn suffix(&self) -> &'static str {
+        self.suffix
+    }
+
     /// Set to a new the value.
     pub fn set(&mut self, value: u128) {
-        *self = Self {
-            literal: Literal::u128_unsuffixed(value),
-            value,
-        };
+        *self = Self::new(value);
     }
 
     /// Deconstructs `self` and gets the `Literal`
@@ -55,17 +88,45 @@ impl LiteralInteger {
     pub fn into_inner(self) -> Literal {
         self.literal
     }
+
+    /// Splits the text of a integer literal into radix, digits and suffix.
+    fn split_integer(text: &str) -> Option<(u32, &str, &'static str)> {
+        let (radix, rest) = match text.get(..2) {
+            Some("0x") => (16, &text[2..]),
+            Some("0o") => (8, &text[2..]),
+            Some("0b") => (2, &text[2..]),
+            _ => (10, text),
+        };
+        let (digits, suffix) = INTEGER_SUFFIXES
+            .iter()
+            .find_map(|suffix| Some((rest.strip_suffix(suffix)?, *suffix)))
+            .unwrap_or((rest, ""));
+        if digits.chars().any(|c| c.is_digit(radix))
+            && digits.chars().all(|c| c == '_' || c.is_digit(radix))
+            && text.starts_with(|c: char| c.is_ascii_digit())
+        {
+            Some((radix, digits, suffix))
+        } else {
+            None
+        }
+    }
 }
 
 impl Parser for LiteralInteger {
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
         let at = tokens.clone().next();
         let literal = Literal::parser(tokens).refine_err::<Self>()?;
-        let value = literal
-            .to_string()
-            .parse()
+        let text = literal.to_string();
+        let Some((radix, digits, suffix)) = Self::split_integer(&text) else {
+            return Error::unexpected_token(at, tokens);
+        };
+        let value = u128::from_str_radix(&digits.replace('_', ""), radix)
             .map_err(|e| Error::dynamic::<Self>(at, tokens, e))?;
-        Ok(Self { literal, value })
+        Ok(Self {
+            literal,
+            value,
+            suffix,
+        })
     }
 }
 
@@ -146,6 +207,318 @@ impl<const V: u128> Default for ConstInteger<V> {
     }
 }
 
+/// A signed 128 bit integer literal, an optional `+` or `-` sign followed by a
+/// [`LiteralInteger`]. The sign token is kept, thus a parsed `+1` is emitted as `+1` again.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "-42 +0x10 7i8".to_token_iter();
+///
+/// let negative = LiteralSignedInteger::parse(&mut token_iter).unwrap();
+/// assert_eq!(negative.value(), -42);
+/// assert!(negative.is_negative());
+/// assert_tokens_eq!(negative, "-42");
+/// assert_eq!(LiteralSignedInteger::parse(&mut token_iter).unwrap().value(), 16);
+/// assert_eq!(LiteralSignedInteger::parse(&mut token_iter).unwrap().suffix(), "i8");
+///
+/// assert_tokens_eq!(LiteralSignedInteger::new(i128::MIN), "-170141183460469231731687303715884105728");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralSignedInteger {
+    /// The sign, if any
+    sign: Option<Either<Plus, Minus>>,
+    /// The unsigned literal following the sign
+    literal: LiteralInteger,
+    /// Value of the integer
+    value: i128,
+}
+
+impl LiteralSignedInteger {
+    /// Create a new `LiteralSignedInteger` from a `i128` value. Negative values get a `-` sign,
+    /// positive values get no sign.
+    #[must_use]
+    pub fn new(value: i128) -> Self {
+        Self {
+            sign: (value < 0).then(|| Either::Second(Minus::new())),
+            literal: LiteralInteger::new(value.unsigned_abs()),
+            value,
+        }
+    }
+
+    /// Get the value.
+    #[must_use]
+    pub const fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Returns `true` when the literal has a `-` sign.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        matches!(self.sign, Some(Either::Second(_)))
+    }
+
+    /// Get the sign token, if any.
+    #[must_use]
+    pub const fn sign(&self) -> Option<&Either<Plus, Minus>> {
+        self.sign.as_ref()
+    }
+
+    /// Get the type suffix such as `"i8"`, empty when there is none.
+    #[must_use]
+    pub const fn suffix(&self) -> &'static str {
+        self.literal.suffix
+    }
+
+    /// Get the unsigned literal following the sign.
+    #[must_use]
+    pub const fn literal(&self) -> &LiteralInteger {
+        &self.literal
+    }
+
+    /// Set to a new the value.
+    pub fn set(&mut self, value: i128) {
+        *self = Self::new(value);
+    }
+}
+
+impl Parser for LiteralSignedInteger {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let Cons {
+            first: sign,
+            second: literal,
+            ..
+        } = Cons::<Option<Either<Plus, Minus>>, LiteralInteger>::parser(tokens)
+            .refine_err::<Self>()?;
+        let value = if matches!(sign, Some(Either::Second(_))) {
+            0i128.checked_sub_unsigned(literal.value)
+        } else {
+            i128::try_from(literal.value).ok()
+        };
+        let Some(value) = value else {
+            return Error::other(
+                at,
+                tokens,
+                format!("integer literal out of range for i128: {}", literal.value),
+            );
+        };
+        Ok(Self {
+            sign,
+            literal,
+            value,
+        })
+    }
+}
+
+impl ToTokens for LiteralSignedInteger {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.sign.to_tokens(tokens);
+        self.literal.to_tokens(tokens);
+    }
+}
+
+impl PartialEq<i128> for LiteralSignedInteger {
+    fn eq(&self, other: &i128) -> bool {
+        &self.value == other
+    }
+}
+
+/// A constant `i128` integer of value `V`, with optional sign. Must match V and also has
+/// `Default` implemented to create a `LiteralSignedInteger` with value `V`.
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "-1 foo".to_token_iter();
+///
+/// let _ = ConstSignedInteger::<-1>::parser(&mut token_iter).unwrap();
+/// let parsed = <OrDefault<i32, ConstSignedInteger<-1234>>>::parser(&mut token_iter).unwrap();
+/// assert_tokens_eq!(parsed, "-1234");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConstSignedInteger<const V: i128>(LiteralSignedInteger);
+
+impl<const V: i128> ConstSignedInteger<V> {
+    /// Get the value.
+    #[must_use]
+    pub const fn value(&self) -> i128 {
+        self.0.value
+    }
+
+    /// Deconstructs `self` and gets the `LiteralSignedInteger`
+    #[must_use]
+    pub fn into_inner(self) -> LiteralSignedInteger {
+        self.0
+    }
+}
+
+impl<const V: i128> Parser for ConstSignedInteger<V> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        Parse::parse_with(tokens, |this: LiteralSignedInteger, e| {
+            if this.value == V {
+                Ok(Self(this))
+            } else {
+                Error::unexpected_token(at, e)
+            }
+        })
+        .refine_err::<Self>()
+    }
+}
+
+impl<const V: i128> ToTokens for ConstSignedInteger<V> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<const V: i128> Default for ConstSignedInteger<V> {
+    fn default() -> Self {
+        Self(LiteralSignedInteger::new(V))
+    }
+}
+
+/// A decimal floating point literal (`1.5`, `2e10`, `1_000.0f32`). Exponents, underscores and
+/// the `f32`/`f64` suffixes are supported. Integer literals without a `f32`/`f64` suffix are
+/// not floats and are rejected, as are signs which are separate tokens.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "1_000.5 2.5e-3f32 42".to_token_iter();
+///
+/// let float = LiteralFloat::parse(&mut token_iter).unwrap();
+/// assert_eq!(float.value(), 1000.5);
+/// assert_eq!(float.suffix(), "");
+///
+/// let float = LiteralFloat::parse(&mut token_iter).unwrap();
+/// assert_eq!(float.value(), 0.0025);
+/// assert_eq!(float.suffix(), "f32");
+///
+/// assert!(LiteralFloat::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralFloat {
+    /// Literal representing a float
+    literal: Literal,
+    /// Value of the float
+    value: f64,
+    /// The `f32` or `f64` suffix, empty when there is none
+    suffix: &'static str,
+}
+
+impl LiteralFloat {
+    /// Create a new unsuffixed `LiteralFloat` from a `f64` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `value` is negative, infinite or NaN.
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        assert!(
+            value.is_finite() && value.is_sign_positive(),
+            "LiteralFloat must be finite and positive"
+        );
+        let literal = Literal::f64_unsuffixed(value);
+        Self {
+            literal,
+            value,
+            suffix: "",
+        }
+    }
+
+    /// Get the value.
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the suffix, this is `"f32"`, `"f64"` or empty.
+    #[must_use]
+    pub const fn suffix(&self) -> &'static str {
+        self.suffix
+    }
+
+    /// Set to a new unsuffixed value.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `value` is negative, infinite or NaN.
+    pub fn set(&mut self, value: f64) {
+        *self = Self::new(value);
+    }
+
+    /// Deconstructs `self` and gets the `Literal`
+    #[must_use]
+    pub fn into_inner(self) -> Literal {
+        self.literal
+    }
+
+    /// Parses the literal text as `F`. Used to convert to `f32` without rounding through the
+    /// stored `f64` value.
+    pub(crate) fn parse_value<F: std::str::FromStr>(&self) -> Option<F> {
+        Self::parse_float(&self.literal.to_string()).map(|(value, _)| value)
+    }
+
+    /// Parses the text of a float literal, returns the value and suffix.
+    fn parse_float<F: std::str::FromStr>(text: &str) -> Option<(F, &'static str)> {
+        let (number, suffix) = if let Some(number) = text.strip_suffix("f32") {
+            (number, "f32")
+        } else if let Some(number) = text.strip_suffix("f64") {
+            (number, "f64")
+        } else {
+            (text, "")
+        };
+        let number = number.strip_suffix('_').unwrap_or(number);
+        let is_float = !suffix.is_empty() || number.contains(['.', 'e', 'E']);
+        if is_float
+            && number.starts_with(|c: char| c.is_ascii_digit())
+            && number
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '_' | '.' | 'e' | 'E' | '+' | '-'))
+        {
+            Some((number.replace('_', "").parse().ok()?, suffix))
+        } else {
+            None
+        }
+    }
+}
+
+impl Parser for LiteralFloat {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let literal = Literal::parser(tokens).refine_err::<Self>()?;
+        match Self::parse_float(&literal.to_string()) {
+            Some((value, suffix)) => Ok(Self {
+                literal,
+                value,
+                suffix,
+            }),
+            None => Error::unexpected_token(at, tokens),
+        }
+    }
+}
+
+impl ToTokens for LiteralFloat {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.literal.to_tokens(tokens);
+    }
+}
+
+impl PartialEq<f64> for LiteralFloat {
+    fn eq(&self, other: &f64) -> bool {
+        &self.value == other
+    }
+}
+
+impl From<LiteralFloat> for TokenTree {
+    fn from(lit: LiteralFloat) -> Self {
+        TokenTree::Literal(lit.into_inner())
+    }
+}
+
 /// A single quoted character literal (`'x'`).
 #[derive(Debug, Clone)]
 pub struct LiteralCharacter {
@@ -188,14 +561,15 @@ impl Parser for LiteralCharacter {
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
         let at = tokens.clone().next();
         let literal = Literal::parser(tokens).refine_err::<Self>()?;
-        let string = literal.to_string();
-        let mut chars = string.chars();
-        // We only need to to check for first single quote, since the lexer already checked
-        // for proper literals
-        if let (Some('\''), Some(value)) = (chars.next(), chars.next()) {
-            Ok(Self { literal, value })
-        } else {
-            Error::unexpected_token(at, tokens)
+        let source = literal.to_string();
+        let value = source
+            .strip_prefix('\'')
+            .and_then(|rest| rest.strip_suffix('\''))
+            .and_then(|content| unescape_str(content, false));
+        let mut chars = value.as_deref().unwrap_or_default().chars();
+        match (chars.next(), chars.next()) {
+            (Some(value), None) => Ok(Self { literal, value }),
+            _ => Error::unexpected_token(at, tokens),
         }
     }
 }
@@ -277,61 +651,113 @@ impl<const V: char> Default for ConstCharacter<V> {
     }
 }
 
-/// A double quoted string literal (`"hello"`). The quotes are included in the value.  Note
-/// that this is a simplified string literal, and only double quoted strings are supported,
-/// this is *not* full rust syntax, eg. byte and C string literals are not supported.
+/// A double quoted string literal (`"hello"`) or raw string literal (`r#"hello"#`) with any
+/// number of hashes. [`unescaped()`](LiteralString::unescaped) returns the unescaped content,
+/// [`value()`](LiteralString::value) the literal as written. Note that byte and C string
+/// literals are not supported, this is *not* full rust syntax.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r###" "a\tb\u{21}" r#"raw "quoted" \n"# "###.to_token_iter();
+///
+/// let string = LiteralString::parse(&mut token_iter).unwrap();
+/// assert_eq!(string.unescaped(), "a\tb!");
+/// assert_eq!(string.as_str(), r"a\tb\u{21}");
+///
+/// let raw = LiteralString::parse(&mut token_iter).unwrap();
+/// assert!(raw.is_raw());
+/// assert_eq!(raw.unescaped(), r#"raw "quoted" \n"#);
+///
+/// let escaped = LiteralString::from_str("line\n\"quoted\"");
+/// assert_tokens_eq!(escaped, r#" "line\n\"quoted\"" "#);
+/// ```
 #[derive(Debug, Clone)]
 pub struct LiteralString {
-    /// Literal representing a double quoted string
+    /// Literal representing a string
     literal: Literal,
-    /// The string value
-    value: String,
+    /// The literal as written, including quotes
+    source: String,
+    /// The unescaped content
+    unescaped: String,
 }
 
 impl LiteralString {
-    /// Create a new `LiteralString` from a `String` value. The supplied `String` must start
-    /// and end with a double quote.
+    /// Create a new `LiteralString` from the source of a string literal. The supplied `String`
+    /// must start and end with a double quote, escapes are taken as written.
     ///
     /// # Panics
     ///
-    /// Panics if the string does not start and end with a double quote.
+    /// Panics if the string does not start and end with a double quote or contains invalid
+    /// escapes.
     #[must_use]
     pub fn new(value: String) -> Self {
         assert!(value.starts_with('"') && value.ends_with('"'));
-        let literal = Literal::string(&value);
-        Self { literal, value }
+        Self::from_source(value).expect("invalid string literal")
     }
 
-    /// Create a new `LiteralString` from any `AsRef<str>` slice. Adds double quotes around
-    /// the supplied string.
+    /// Create a new `LiteralString` from any `AsRef<str>` slice. The string is the unescaped
+    /// value, it becomes escaped and enclosed in double quotes when emitted.
     #[must_use]
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(string: impl AsRef<str>) -> Self {
         let string = string.as_ref();
-        let value = format!(r#""{string}""#);
         let literal = Literal::string(string);
-        Self { literal, value }
+        Self {
+            source: literal.to_string(),
+            literal,
+            unescaped: string.to_string(),
+        }
+    }
+
+    /// Creates a `LiteralString` from the source of a (raw) string literal.
+    fn from_source(source: String) -> Option<Self> {
+        let (content, raw) = split_string(&source, "")?;
+        let unescaped = unescape_str(content, raw)?;
+        let literal = source.parse().ok()?;
+        Some(Self {
+            literal,
+            source,
+            unescaped,
+        })
     }
 
-    /// Get the `&str` including the surrounding quotes.
+    /// Get the `&str` including the surrounding quotes, for raw strings also the `r` and
+    /// hashes. This is the literal as written, escapes are not processed.
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // bug in clippy
     pub fn value(&self) -> &str {
-        &self.value
+        &self.source
     }
 
-    /// Get the `&str` with the surrounding quotes removed.
+    /// Get the unescaped content of the string.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // bug in clippy
+    pub fn unescaped(&self) -> &str {
+        &self.unescaped
+    }
+
+    /// Get the `&str` with the surrounding quotes removed. Escapes are not processed.
     #[must_use]
     pub fn as_str(&self) -> &str {
-        &self.value[1..self.value.len() - 1]
+        split_string(&self.source, "").map_or("", |(content, _)| content)
+    }
+
+    /// Returns `true` when this is a raw string literal.
+    #[must_use]
+    pub fn is_raw(&self) -> bool {
+        self.source.starts_with('r')
     }
 
-    /// Set the value to a new `String`.
+    /// Set to the source of a new string literal, see [`new()`](LiteralString::new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string does not start and end with a double quote or contains invalid
+    /// escapes.
     pub fn set(&mut self, value: String) {
-        *self = Self {
-            literal: Literal::string(&value),
-            value,
-        };
+        *self = Self::new(value);
     }
 
     /// Deconstructs `self` and gets the `Literal`
@@ -341,20 +767,103 @@ impl LiteralString {
     }
 }
 
+/// Splits the source of a string literal with the given `prefix` (`""`, `"b"` or `"c"`) into
+/// its content and whether it is a raw string.
+fn split_string<'a>(source: &'a str, prefix: &str) -> Option<(&'a str, bool)> {
+    let source = source.strip_prefix(prefix)?;
+    if let Some(rest) = source.strip_prefix('r') {
+        let hashes = &rest[..rest.len() - rest.trim_start_matches('#').len()];
+        let content = rest[hashes.len()..]
+            .strip_prefix('"')?
+            .strip_suffix(hashes)?
+            .strip_suffix('"')?;
+        Some((content, true))
+    } else {
+        Some((source.strip_prefix('"')?.strip_suffix('"')?, false))
+    }
+}
+
+/// The escapes permitted in the different kinds of literals.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escapes {
+    /// String and character literals, `\x` up to `0x7f`, unicode permitted
+    Str,
+    /// Byte and byte string literals, `\x` up to `0xff`, ASCII only
+    Bytes,
+    /// C string literals, `\x` up to `0xff`, unicode permitted, no NUL
+    CStr,
+}
+
+/// Decodes the content of a literal. Returns `None` on invalid escapes or characters.
+fn unescape(content: &str, raw: bool, escapes: Escapes) -> Option<Vec<u8>> {
+    let mut value = Vec::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut buf = [0; 4];
+    while let Some(c) = chars.next() {
+        if c != '\\' || raw {
+            if escapes == Escapes::Bytes && !c.is_ascii() {
+                return None;
+            }
+            value.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push(b'\n'),
+            'r' => value.push(b'\r'),
+            't' => value.push(b'\t'),
+            '0' => value.push(0),
+            c @ ('\\' | '\'' | '"') => value.push(c as u8),
+            'x' => {
+                let code =
+                    u8::from_str_radix(&chars.by_ref().take(2).collect::<String>(), 16).ok()?;
+                if escapes == Escapes::Str && code > 0x7f {
+                    return None;
+                }
+                value.push(code);
+            }
+            'u' if escapes != Escapes::Bytes => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                let c = char::from_u32(u32::from_str_radix(&code.replace('_', ""), 16).ok()?)?;
+                value.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+            '\n' => {
+                // line continuation, skip leading whitespace of the next line
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            }
+            _ => return None,
+        }
+    }
+    if escapes == Escapes::CStr && value.contains(&0) {
+        return None;
+    }
+    Some(value)
+}
+
+/// Decodes the content of a string literal.
+fn unescape_str(content: &str, raw: bool) -> Option<String> {
+    String::from_utf8(unescape(content, raw, Escapes::Str)?).ok()
+}
+
 impl Parser for LiteralString {
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
         let at = tokens.clone().next();
         let literal = Literal::parser(tokens).refine_err::<Self>()?;
-        let string = literal.to_string();
+        let source = literal.to_string();
         // The lexer did its job here as well
-        if &string[0..1] == "\"" {
-            Ok(Self {
-                literal,
-                value: string,
-            })
-        } else {
-            Error::unexpected_token(at, tokens)
-        }
+        let Some((content, raw)) = split_string(&source, "") else {
+            return Error::unexpected_token(at, tokens);
+        };
+        let Some(unescaped) = unescape_str(content, raw) else {
+            return Error::other(at, tokens, format!("invalid escape in {source}"));
+        };
+        Ok(Self {
+            literal,
+            source,
+            unescaped,
+        })
     }
 }
 
@@ -364,10 +873,10 @@ impl ToTokens for LiteralString {
     }
 }
 
-/// Compares without the surrounding quotes.
+/// Compares the unescaped value.
 impl PartialEq<&str> for LiteralString {
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self.unescaped == *other
     }
 }
 
@@ -382,3 +891,589 @@ fn test_literalstring_into_tt() {
     let lit = LiteralString::from_str("foobar");
     let _: TokenTree = lit.into();
 }
+
+/// A byte literal (`b'a'`, `b'\\xff'`).
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r"b'a' b'\n' 'a'".to_token_iter();
+///
+/// assert_eq!(LiteralByte::parse(&mut token_iter).unwrap().value(), b'a');
+/// assert_eq!(LiteralByte::parse(&mut token_iter).unwrap().value(), b'\n');
+/// assert!(LiteralByte::parse(&mut token_iter).is_err());
+/// assert_tokens_eq!(LiteralByte::new(b'x'), "b'x'");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralByte {
+    /// Literal representing a byte
+    literal: Literal,
+    /// The byte value
+    value: u8,
+}
+
+impl LiteralByte {
+    /// Create a new `LiteralByte` from a `u8` value.
+    #[must_use]
+    pub fn new(value: u8) -> Self {
+        let literal = Literal::byte_character(value);
+        Self { literal, value }
+    }
+
+    /// Get the value.
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Set to a new value.
+    pub fn set(&mut self, value: u8) {
+        *self = Self::new(value);
+    }
+
+    /// Deconstructs `self` and gets the `Literal`
+    #[must_use]
+    pub fn into_inner(self) -> Literal {
+        self.literal
+    }
+}
+
+impl Parser for LiteralByte {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let literal = Literal::parser(tokens).refine_err::<Self>()?;
+        let source = literal.to_string();
+        let value = source
+            .strip_prefix("b'")
+            .and_then(|rest| rest.strip_suffix('\''))
+            .and_then(|content| unescape(content, false, Escapes::Bytes));
+        match value.as_deref() {
+            Some(&[value]) => Ok(Self { literal, value }),
+            _ => Error::unexpected_token(at, tokens),
+        }
+    }
+}
+
+impl ToTokens for LiteralByte {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.literal.to_tokens(tokens);
+    }
+}
+
+impl PartialEq<u8> for LiteralByte {
+    fn eq(&self, other: &u8) -> bool {
+        &self.value == other
+    }
+}
+
+impl From<LiteralByte> for TokenTree {
+    fn from(lit: LiteralByte) -> Self {
+        TokenTree::Literal(lit.into_inner())
+    }
+}
+
+/// A byte string literal (`b"bytes"`) or raw byte string literal (`br#"bytes"#`).
+/// [`value()`](LiteralByteString::value) returns the decoded bytes.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r##" b"\x00\xffab" br#"\n"# "##.to_token_iter();
+///
+/// let bytes = LiteralByteString::parse(&mut token_iter).unwrap();
+/// assert_eq!(bytes.value(), &[0x00, 0xff, b'a', b'b']);
+/// let raw = LiteralByteString::parse(&mut token_iter).unwrap();
+/// assert!(raw.is_raw());
+/// assert_eq!(raw.value(), br"\n");
+///
+/// let blob = LiteralByteString::from_bytes([1, 2, 3]);
+/// assert_tokens_eq!(blob, r#" b"\x01\x02\x03" "#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralByteString {
+    /// Literal representing a byte string
+    literal: Literal,
+    /// The literal as written, including prefix and quotes
+    source: String,
+    /// The decoded bytes
+    value: Vec<u8>,
+}
+
+impl LiteralByteString {
+    /// Create a new `LiteralByteString` from bytes, they become escaped when emitted.
+    #[must_use]
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+        let literal = Literal::byte_string(bytes);
+        Self {
+            source: literal.to_string(),
+            literal,
+            value: bytes.to_vec(),
+        }
+    }
+
+    /// Get the decoded bytes.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // bug in clippy
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Get the literal as written, including prefix and quotes.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // bug in clippy
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` when this is a raw byte string literal.
+    #[must_use]
+    pub fn is_raw(&self) -> bool {
+        self.source.starts_with("br")
+    }
+
+    /// Set to new bytes.
+    pub fn set(&mut self, bytes: impl AsRef<[u8]>) {
+        *self = Self::from_bytes(bytes);
+    }
+
+    /// Deconstructs `self` and gets the `Literal`
+    #[must_use]
+    pub fn into_inner(self) -> Literal {
+        self.literal
+    }
+}
+
+impl Parser for LiteralByteString {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let literal = Literal::parser(tokens).refine_err::<Self>()?;
+        let source = literal.to_string();
+        match split_string(&source, "b")
+            .and_then(|(content, raw)| unescape(content, raw, Escapes::Bytes))
+        {
+            Some(value) => Ok(Self {
+                literal,
+                source,
+                value,
+            }),
+            None => Error::unexpected_token(at, tokens),
+        }
+    }
+}
+
+impl ToTokens for LiteralByteString {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.literal.to_tokens(tokens);
+    }
+}
+
+impl PartialEq<&[u8]> for LiteralByteString {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.value == *other
+    }
+}
+
+impl From<LiteralByteString> for TokenTree {
+    fn from(lit: LiteralByteString) -> Self {
+        TokenTree::Literal(lit.into_inner())
+    }
+}
+
+/// A C string literal (`c"text"`) or raw C string literal (`cr#"text"#`).
+/// [`value()`](LiteralCString::value) returns the decoded `&CStr`,
+/// [`as_bytes()`](LiteralCString::as_bytes) its bytes without the terminating NUL.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#" c"h\xffi\u{e9}" "#.to_token_iter();
+///
+/// let cstr = LiteralCString::parse(&mut token_iter).unwrap();
+/// assert_eq!(cstr.as_bytes(), b"h\xffi\xc3\xa9");
+/// assert_eq!(cstr.value().to_bytes_with_nul().last(), Some(&0));
+/// assert_tokens_eq!(cstr, r#" c"h\xffi\u{e9}" "#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiteralCString {
+    /// Literal representing a C string
+    literal: Literal,
+    /// The literal as written, including prefix and quotes
+    source: String,
+    /// The decoded string
+    value: CString,
+}
+
+impl LiteralCString {
+    /// Create a new `LiteralCString` from a `&CStr`, it becomes escaped when emitted.
+    #[must_use]
+    pub fn from_c_str(string: &CStr) -> Self {
+        let literal = Literal::c_string(string);
+        Self {
+            source: literal.to_string(),
+            literal,
+            value: string.to_owned(),
+        }
+    }
+
+    /// Get the decoded string.
+    #[must_use]
+    pub fn value(&self) -> &CStr {
+        &self.value
+    }
+
+    /// Get the decoded bytes without the terminating NUL.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.value.as_bytes()
+    }
+
+    /// Get the literal as written, including prefix and quotes.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // bug in clippy
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` when this is a raw C string literal.
+    #[must_use]
+    pub fn is_raw(&self) -> bool {
+        self.source.starts_with("cr")
+    }
+
+    /// Set to a new string.
+    pub fn set(&mut self, string: &CStr) {
+        *self = Self::from_c_str(string);
+    }
+
+    /// Deconstructs `self` and gets the `Literal`
+    #[must_use]
+    pub fn into_inner(self) -> Literal {
+        self.literal
+    }
+}
+
+impl Parser for LiteralCString {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let literal = Literal::parser(tokens).refine_err::<Self>()?;
+        let source = literal.to_string();
+        match split_string(&source, "c")
+            .and_then(|(content, raw)| unescape(content, raw, Escapes::CStr))
+            .and_then(|bytes| CString::new(bytes).ok())
+        {
+            Some(value) => Ok(Self {
+                literal,
+                source,
+                value,
+            }),
+            None => Error::unexpected_token(at, tokens),
+        }
+    }
+}
+
+impl ToTokens for LiteralCString {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.literal.to_tokens(tokens);
+    }
+}
+
+impl PartialEq<&CStr> for LiteralCString {
+    fn eq(&self, other: &&CStr) -> bool {
+        self.value.as_c_str() == *other
+    }
+}
+
+impl From<LiteralCString> for TokenTree {
+    fn from(lit: LiteralCString) -> Self {
+        TokenTree::Literal(lit.into_inner())
+    }
+}
+
+/// Any literal, classified by its kind. Parsing inspects the literal once and dispatches to
+/// the matching `Literal*` type. Raw string literals get their own variants.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#" 42 1.5 'c' b'b' "s" r"raw" b"bytes" c"cstr" "#.to_token_iter();
+///
+/// let kinds = Vec::<LiteralKind>::parse(&mut token_iter).unwrap();
+/// assert_eq!(kinds[0].integer(), Some(42));
+/// assert_eq!(kinds[1].float(), Some(1.5));
+/// assert_eq!(kinds[2].character(), Some('c'));
+/// assert_eq!(kinds[3].byte(), Some(b'b'));
+/// assert_eq!(kinds[4].string(), Some("s"));
+/// assert!(matches!(kinds[5], LiteralKind::RawString(_)));
+/// assert_eq!(kinds[5].string(), Some("raw"));
+/// assert_eq!(kinds[6].byte_string(), Some(&b"bytes"[..]));
+/// assert_eq!(kinds[7].c_string(), Some(c"cstr"));
+/// assert_eq!(kinds[7].string(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub enum LiteralKind {
+    /// An integer literal
+    Integer(LiteralInteger),
+    /// A floating point literal
+    Float(LiteralFloat),
+    /// A character literal
+    Character(LiteralCharacter),
+    /// A byte literal
+    Byte(LiteralByte),
+    /// A string literal
+    String(LiteralString),
+    /// A raw string literal
+    RawString(LiteralString),
+    /// A byte string literal
+    ByteString(LiteralByteString),
+    /// A raw byte string literal
+    RawByteString(LiteralByteString),
+    /// A C string literal
+    CString(LiteralCString),
+    /// A raw C string literal
+    RawCString(LiteralCString),
+}
+
+impl LiteralKind {
+    /// Get the value of an integer literal.
+    #[must_use]
+    pub const fn integer(&self) -> Option<u128> {
+        match self {
+            Self::Integer(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a floating point literal.
+    #[must_use]
+    pub const fn float(&self) -> Option<f64> {
+        match self {
+            Self::Float(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a character literal.
+    #[must_use]
+    pub const fn character(&self) -> Option<char> {
+        match self {
+            Self::Character(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a byte literal.
+    #[must_use]
+    pub const fn byte(&self) -> Option<u8> {
+        match self {
+            Self::Byte(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Get the unescaped value of a string or raw string literal.
+    #[must_use]
+    pub fn string(&self) -> Option<&str> {
+        match self {
+            Self::String(lit) | Self::RawString(lit) => Some(lit.unescaped()),
+            _ => None,
+        }
+    }
+
+    /// Get the decoded bytes of a byte string or raw byte string literal.
+    #[must_use]
+    pub fn byte_string(&self) -> Option<&[u8]> {
+        match self {
+            Self::ByteString(lit) | Self::RawByteString(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Get the decoded value of a C string or raw C string literal.
+    #[must_use]
+    pub fn c_string(&self) -> Option<&CStr> {
+        match self {
+            Self::CString(lit) | Self::RawCString(lit) => Some(lit.value()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for raw string, raw byte string and raw C string literals.
+    #[must_use]
+    pub const fn is_raw(&self) -> bool {
+        matches!(
+            self,
+            Self::RawString(_) | Self::RawByteString(_) | Self::RawCString(_)
+        )
+    }
+}
+
+impl Parser for LiteralKind {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let Some(TokenTree::Literal(literal)) = &at else {
+            return Error::unexpected_token(at, tokens);
+        };
+        let source = literal.to_string();
+        match source.as_bytes() {
+            [b'"', ..] => LiteralString::parser(tokens).map(Self::String),
+            [b'r', ..] => LiteralString::parser(tokens).map(Self::RawString),
+            [b'\'', ..] => LiteralCharacter::parser(tokens).map(Self::Character),
+            [b'b', b'\'', ..] => LiteralByte::parser(tokens).map(Self::Byte),
+            [b'b', b'"', ..] => LiteralByteString::parser(tokens).map(Self::ByteString),
+            [b'b', b'r', ..] => LiteralByteString::parser(tokens).map(Self::RawByteString),
+            [b'c', b'"', ..] => LiteralCString::parser(tokens).map(Self::CString),
+            [b'c', b'r', ..] => LiteralCString::parser(tokens).map(Self::RawCString),
+            _ if LiteralInteger::split_integer(&source).is_some() => {
+                LiteralInteger::parser(tokens).map(Self::Integer)
+            }
+            _ => LiteralFloat::parser(tokens).map(Self::Float),
+        }
+        .refine_err::<Self>()
+    }
+}
+
+impl ToTokens for LiteralKind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Integer(lit) => lit.to_tokens(tokens),
+            Self::Float(lit) => lit.to_tokens(tokens),
+            Self::Character(lit) => lit.to_tokens(tokens),
+            Self::Byte(lit) => lit.to_tokens(tokens),
+            Self::String(lit) | Self::RawString(lit) => lit.to_tokens(tokens),
+            Self::ByteString(lit) | Self::RawByteString(lit) => lit.to_tokens(tokens),
+            Self::CString(lit) | Self::RawCString(lit) => lit.to_tokens(tokens),
+        }
+    }
+}
+
+impl From<LiteralKind> for TokenTree {
+    fn from(lit: LiteralKind) -> Self {
+        match lit {
+            LiteralKind::Integer(lit) => lit.into(),
+            LiteralKind::Float(lit) => lit.into(),
+            LiteralKind::Character(lit) => lit.into(),
+            LiteralKind::Byte(lit) => lit.into(),
+            LiteralKind::String(lit) | LiteralKind::RawString(lit) => lit.into(),
+            LiteralKind::ByteString(lit) | LiteralKind::RawByteString(lit) => lit.into(),
+            LiteralKind::CString(lit) | LiteralKind::RawCString(lit) => lit.into(),
+        }
+    }
+}
+
+/// Selects the literal type for a [`literal!`] constant by the kind of its source, see
+/// [`const_literal_kind()`].
+#[doc(hidden)]
+pub struct ConstLiteralSelect<const KIND: u8>;
+
+/// The literal type and rust value type used for [`literal!`] constants of some kind.
+#[doc(hidden)]
+pub trait ConstLiteralType {
+    /// The parser for the literal
+    type Literal: Parser + ToTokens + Clone + std::fmt::Debug;
+    /// The rust type of the constant
+    type Value: Copy + std::fmt::Debug;
+
+    /// Create the literal for `value`.
+    fn new(value: Self::Value) -> Self::Literal;
+
+    /// Returns `true` when `literal` has the value `value`.
+    fn matches(literal: &Self::Literal, value: Self::Value) -> bool;
+}
+
+/// A string literal constant
+const STR: u8 = 0;
+/// A character literal constant
+const CHAR: u8 = 1;
+/// A `true` or `false` constant
+const BOOL: u8 = 2;
+/// A integer constant, possibly negative
+const INTEGER: u8 = 3;
+/// A floating point constant, possibly negative
+const FLOAT: u8 = 4;
+/// A byte literal constant
+const BYTE: u8 = 5;
+/// A byte string literal constant
+const BYTE_STRING: u8 = 6;
+/// A C string literal constant
+const C_STRING: u8 = 7;
+
+/// Determines the kind of a literal from its source as given by `stringify!`. Panics for
+/// unsupported literals, at compile time when used in a const context.
+#[doc(hidden)]
+#[must_use]
+pub const fn const_literal_kind(source: &str) -> u8 {
+    let bytes = source.as_bytes();
+    assert!(!bytes.is_empty(), "empty literal");
+    match bytes {
+        [b'"' | b'r', ..] => return STR,
+        [b'\'', ..] => return CHAR,
+        [b'b', b'\'', ..] => return BYTE,
+        [b'b', b'"' | b'r', ..] => return BYTE_STRING,
+        [b'c', b'"' | b'r', ..] => return C_STRING,
+        b"true" | b"false" => return BOOL,
+        _ => {}
+    }
+    // numbers, skip the sign
+    let mut i = 0;
+    while i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b' ') {
+        i += 1;
+    }
+    assert!(
+        i < bytes.len() && bytes[i].is_ascii_digit(),
+        "unsupported literal"
+    );
+    let prefixed =
+        i + 1 < bytes.len() && bytes[i] == b'0' && matches!(bytes[i + 1], b'x' | b'o' | b'b');
+    let mut kind = INTEGER;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'u' | b'i' => panic!("literal suffixes are not supported, remove the suffix"),
+            b'f' if !prefixed => panic!("literal suffixes are not supported, remove the suffix"),
+            b'.' | b'e' | b'E' if !prefixed => kind = FLOAT,
+            _ => {}
+        }
+        i += 1;
+    }
+    kind
+}
+
+macro_rules! impl_const_literal_type {
+    ($($kind:ident: $literal:ty, $value:ty, $new:expr, $matches:expr;)*) => {
+        $(
+            impl ConstLiteralType for ConstLiteralSelect<$kind> {
+                type Literal = $literal;
+                type Value = $value;
+
+                fn new(value: Self::Value) -> Self::Literal {
+                    $new(value)
+                }
+
+                fn matches(literal: &Self::Literal, value: Self::Value) -> bool {
+                    $matches(literal, value)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_literal_type! {
+    STR: LiteralString, &'static str, LiteralString::from_str,
+        |literal: &LiteralString, value| literal.unescaped() == value;
+    CHAR: LiteralCharacter, char, LiteralCharacter::new,
+        |literal: &LiteralCharacter, value| literal.value() == value;
+    BOOL: bool, bool, std::convert::identity, |literal: &bool, value| *literal == value;
+    INTEGER: LiteralSignedInteger, i128, LiteralSignedInteger::new,
+        |literal: &LiteralSignedInteger, value| literal.value() == value;
+    FLOAT: f64, f64, std::convert::identity,
+        |literal: &f64, value: f64| literal.to_bits() == value.to_bits();
+    BYTE: LiteralByte, u8, LiteralByte::new, |literal: &LiteralByte, value| literal.value() == value;
+    BYTE_STRING: LiteralByteString, &'static [u8], LiteralByteString::from_bytes,
+        |literal: &LiteralByteString, value| literal.value() == value;
+    C_STRING: LiteralCString, &'static CStr, LiteralCString::from_c_str,
+        |literal: &LiteralCString, value| literal.value() == value;
+}
@@ -7,9 +7,10 @@ use crate::*;
 /// generates [`Parser`] and [`ToTokens`] implementations for them. It will derive `Debug`.
 /// Generics/Lifetimes are not supported on the primary type.  Note: eventually a derive macro
 /// for `Parser` and `ToTokens` will become supported by a 'unsynn-derive' crate to give finer
-/// control over the expansion. `#[derive(Copy, Clone)]` have to be manually defined. Keyword
-/// and operator definitions can also be defined, they delegate to the `keyword!` and
-/// `operator!` macro described below. All entities can be prefixed by `pub` to make them
+/// control over the expansion. `#[derive(Copy, Clone)]` have to be manually defined. Keyword,
+/// operator and literal definitions can also be defined, they delegate to the `keyword!`,
+/// `operator!` and `literal!` macro described below. Structs parsed from meta argument lists can be defined
+/// with `meta struct`, this delegates to the `meta_args!` macro. All entities can be prefixed by `pub` to make them
 /// public. Type aliases, function definitions, macros and use statements are passed through. This
 /// makes thing easier readable when you define larger unsynn macro blocks.
 ///
@@ -192,6 +193,10 @@ macro_rules! unsynn {
     (keyword $name:ident = keyword_or_group;) => {};
     (keyword $name:ident != keyword_or_group;) => {};
     (operator $name:ident = "punct";) => {};
+    (operator $name:ident = [operators];) => {};
+    (operator $name:ident != [operators];) => {};
+    (literal $name:ident = literal;) => {};
+    (meta struct $name:ident { $($member:ident: $field:ty),* }) => {};
 }
 
 #[doc(hidden)]
@@ -279,6 +284,7 @@ macro_rules! unsynn{
         $(where
             $($where: $wherebound $(+ $wherebounds)*),*)?
         {
+            #[allow(clippy::result_large_err)]
             fn parser(tokens: &mut TokenIter) -> $crate::Result<Self> {
                 let mut err = Error::no_error();
                 // try to parse each variant
@@ -731,6 +737,68 @@ macro_rules! unsynn{
         $crate::operator!{$(#[$attribute])* $pub $name = $str}
         $crate::unsynn!{$($cont)*}
     };
+    (
+        $(#[$attribute:meta])* $pub:vis operator $name:ident = [$($operators:tt),+ $(,)?];
+        impl {$($(#[$tattr:meta])* $trait:ident $bracesemi:tt)*}
+        $($cont:tt)*
+    ) => {
+        $crate::operator!{$(#[$attribute])* $pub $name = [$($operators),+]}
+        $crate::unsynn!{
+            @impl
+            for $name
+            {$({$(#[$tattr])* $trait $bracesemi})*}
+        }
+        $crate::unsynn!{$($cont)*}
+    };
+    ($(#[$attribute:meta])* $pub:vis operator $name:ident = [$($operators:tt),+ $(,)?]; $($cont:tt)*) => {
+        $crate::operator!{$(#[$attribute])* $pub $name = [$($operators),+]}
+        $crate::unsynn!{$($cont)*}
+    };
+    (
+        $(#[$attribute:meta])* $pub:vis operator $name:ident != [$($operators:tt),+ $(,)?];
+        impl {$($(#[$tattr:meta])* $trait:ident $bracesemi:tt)*}
+        $($cont:tt)*
+    ) => {
+        $crate::operator!{$(#[$attribute])* $pub $name != [$($operators),+]}
+        $crate::unsynn!{
+            @impl
+            for $name
+            {$({$(#[$tattr])* $trait $bracesemi})*}
+        }
+        $crate::unsynn!{$($cont)*}
+    };
+    ($(#[$attribute:meta])* $pub:vis operator $name:ident != [$($operators:tt),+ $(,)?]; $($cont:tt)*) => {
+        $crate::operator!{$(#[$attribute])* $pub $name != [$($operators),+]}
+        $crate::unsynn!{$($cont)*}
+    };
+
+    // literal delegation
+    (
+        $(#[$attribute:meta])* $pub:vis literal $name:ident = $lit:literal;
+        impl {$($(#[$tattr:meta])* $trait:ident $bracesemi:tt)*}
+        $($cont:tt)*
+    ) => {
+        $crate::literal!{$(#[$attribute])* $pub $name = $lit}
+        $crate::unsynn!{
+            @impl
+            for $name
+            {$({$(#[$tattr])* $trait $bracesemi})*}
+        }
+        $crate::unsynn!{$($cont)*}
+    };
+    ($(#[$attribute:meta])* $pub:vis literal $name:ident = $lit:literal; $($cont:tt)*) => {
+        $crate::literal!{$(#[$attribute])* $pub $name = $lit}
+        $crate::unsynn!{$($cont)*}
+    };
+
+    // meta args delegation
+    (
+        $(#[$attribute:meta])* $pub:vis meta struct $name:ident {$($fields:tt)*}
+        $($cont:tt)*
+    ) => {
+        $crate::meta_args!{$(#[$attribute])* $pub struct $name {$($fields)*}}
+        $crate::unsynn!{$($cont)*}
+    };
 
     // terminate recursion
     () => {};
@@ -1118,6 +1186,7 @@ macro_rules! keyword{
         $pub struct $name($crate::CachedIdent);
 
         impl $crate::Parser for $name {
+            #[allow(clippy::result_large_err)]
             fn parser(tokens: &mut $crate::TokenIter) -> $crate::Result<Self> {
                 use $crate::Parse;
                 let at = tokens.clone().next();
@@ -1210,11 +1279,23 @@ macro_rules! keyword{
 /// `operator!{ pub Op = "punct"; ...}`
 ///
 /// * A optional `pub` defines the operators public, default is private
-/// * `Op` is the name for the struct to be generated
-/// * `"punct"` is up to 4 ASCII punctuation characters
+/// * `Op` is the name for the type to be defined
+/// * `"punct"` is one to [`OPERATOR_MAX_LEN`] ASCII punctuation characters
+/// * `group` is a non empty bracketed list of `"punct"` or other operator definitions.
+/// * By using `=` the operator must match the given definition while `!=` negates a group
+///   and matches any operator that is not in the group.
+///
+/// `Op::parse()` will match the defined operator, all but the last character must be
+/// [`Spacing::Joint`]. `Op` is a type alias of [`Operator`], its characters are available as
+/// `Op::OPERATOR` and `as_str()`.
 ///
-/// `Op::parse()` will match the defined operator. It will implement `Debug` and `Clone`
-/// for operators.
+/// Operator groups match the longest operator from the group, `>>=` is tried before `>>`
+/// which is tried before `>`. They implement `Debug`, `Clone`, `PartialEq`, `Eq` and
+/// `AsRef<str>`, `as_str()` returns which operator matched. Negated groups match a sequence
+/// of joint punctuation characters as a whole, see [`DynOperator`].
+///
+/// Operators and operator groups that are not negated implement [`SpacingInsensitive`],
+/// wrapping them in [`AnySpacing`] matches them regardless of joint/alone spacing.
 ///
 /// The `unsynn!` macro supports defining operators by using `operator Op = "chars";`, the
 /// `pub` specification has to come before `operator` then.
@@ -1227,9 +1308,13 @@ macro_rules! keyword{
 ///     /// Optional documentation for `<~~`
 ///     WLArrow = "<~~";
 ///     WRArrow = "~~>";
+///     // operators longer than four characters are supported as well
+///     LongArrow = "<===>";
 /// }
 ///
-/// let mut tokens = "<~~~~> ~~><~~".to_token_iter();
+/// let mut tokens = "<===> <~~~~> ~~><~~".to_token_iter();
+/// let long = LongArrow::parse(&mut tokens).unwrap();
+/// assert_eq!(long.as_str(), "<===>");
 /// let wl = WLArrow::parse(&mut tokens).unwrap();
 /// assert_tokens_eq!(wl, str "<~~");
 /// let wr = WRArrow::parse(&mut tokens).unwrap();
@@ -1239,54 +1324,416 @@ macro_rules! keyword{
 /// # let wl = WLArrow::parse(&mut tokens).unwrap();
 /// # assert_tokens_eq!(wl, "<~~");
 /// ```
+///
+/// Operator groups:
+///
+/// ```
+/// # use unsynn::*;
+/// operator!{
+///     Shift = [Shl, Shr];
+///     // groups can contain other operators, groups and strings
+///     Arith = [Plus, Minus, "*", "/", Shift, ">>=", ">"];
+///     NotArith != [Arith];
+/// }
+///
+/// let mut tokens = ">>= > > - ==".to_token_iter();
+/// // longest match
+/// assert_eq!(Arith::parse(&mut tokens).unwrap().as_str(), ">>=");
+/// // `>` is alone
+/// assert_eq!(Arith::parse(&mut tokens).unwrap().as_str(), ">");
+/// let gt = Arith::parse(&mut tokens).unwrap();
+/// assert_tokens_eq!(gt, ">");
+/// assert!(NotArith::parse(&mut tokens).is_err());
+/// # assert_eq!(Arith::parse(&mut tokens).unwrap().as_str(), "-");
+/// assert_eq!(NotArith::parse(&mut tokens).unwrap().as_str(), "==");
+/// ```
 #[macro_export]
 macro_rules! operator{
-    // match a list of operator! defs
-    ($($(#[$attribute:meta])* $pub:vis $name:ident = $op:literal);*$(;)?) => {
-        $(
-            $crate::operator!(@operator $(#[$attribute])* $pub $name = $op);
-        )*
+    ($(#[$attribute:meta])* $pub:vis $name:ident = $op:literal $(;$($cont:tt)*)?) => {
+        $crate::operator!(@operator $(#[$attribute])* $pub $name = $op);
+        $crate::operator!{$($($cont)*)?}
+    };
+    (
+        $(#[$attribute:meta])*
+        $pub:vis $name:ident = [$($operators:tt),+ $(,)?]
+        $(;$($cont:tt)*)?
+    ) => {
+        $crate::operator!{
+            @{} $(#[$attribute])* $pub $name [$($operators),+]
+        }
+        $crate::operator!{$($($cont)*)?}
+    };
+    (
+        $(#[$attribute:meta])*
+        $pub:vis $name:ident != [$($operators:tt),+ $(,)?]
+        $(;$($cont:tt)*)?
+    ) => {
+        $crate::operator!{
+            @{!} $(#[$attribute])* $pub $name [$($operators),+]
+        }
+        $crate::operator!{$($($cont)*)?}
+    };
+    () => {};
+
+    // operator groups
+    (
+        @{$($not:tt)?} $(#[$attribute:meta])*
+        $pub:vis $name:ident [$($operators:tt),+]
+    ) => {
+        $(#[$attribute])*
+        #[doc = concat!(
+             $crate::docgen!{@operator_header $($not)?},
+             $($crate::docgen!{@keyword_doc $operators}),+
+        )]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        $pub struct $name($crate::operator!{@storage $($not)?});
+
+        impl $crate::Parser for $name {
+            fn parser(tokens: &mut $crate::TokenIter) -> $crate::Result<Self> {
+                $crate::operator!{@parse $($not)? $name tokens}.map(Self)
+            }
+        }
+
+        $crate::operator!{@any_spacing $($not)? $name}
+
+        impl $crate::ToTokens for $name {
+            fn to_tokens(&self, tokens: &mut $crate::TokenStream) {
+                $crate::operator_to_tokens(self.as_str(), tokens);
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl $name {
+            /// Gets the characters of the matched operator.
+            #[allow(dead_code)]
+            pub fn as_str(&self) -> &str {
+                self.0.as_ref()
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn operators() -> &'static $crate::KeywordGroup {
+                static OPERATORS: $crate::KeywordGroup = $crate::operator! {@group $($operators),+};
+                &OPERATORS
+            }
+
+            fn candidates() -> &'static [&'static str] {
+                static CANDIDATES: std::sync::LazyLock<Vec<&'static str>> =
+                    std::sync::LazyLock::new(|| $crate::create_operator_list($name::operators()));
+                &CANDIDATES
+            }
+        }
+    };
+    (@storage) => {
+        &'static str
+    };
+    (@storage !) => {
+        $crate::DynOperator
+    };
+    (@parse $name:ident $tokens:ident) => {
+        $crate::parse_operator_group::<$name>($tokens, $name::candidates(), false)
+    };
+    (@parse ! $name:ident $tokens:ident) => {
+        $crate::parse_not_operator_group::<$name>($tokens, $name::candidates())
+    };
+    (@any_spacing $name:ident) => {
+        impl $crate::SpacingInsensitive for $name {
+            fn parse_any_spacing(tokens: &mut $crate::TokenIter) -> $crate::Result<Self> {
+                $crate::parse_operator_group::<$name>(tokens, $name::candidates(), true).map(Self)
+            }
+        }
+    };
+    // negated groups match whole joint sequences, ignoring spacing is not supported
+    (@any_spacing ! $name:ident) => {};
+
+    // operator group creation
+    (@group $($entry:tt),+) => {
+        $crate::KeywordGroup::List(
+            &[$(&$crate::operator!{@entry $entry}),+]
+        )
+    };
+    (@entry $op:literal) => {
+        $crate::KeywordGroup::Keyword({
+            assert!(
+                $crate::is_operator($op),
+                "Operators must be one or more ASCII punctuation characters"
+            );
+            $op
+        })
+    };
+    (@entry $sub:path) => {
+        *<$sub>::operators()
     };
 
-    // match a single operator! defs with len 1-4
+    // match a single operator! def
     (@operator $(#[$attribute:meta])* $pub:vis $name:ident = $op:literal) => {
         $(#[$attribute])*
-        // TODO $crate::docgen!{operator $op},
         #[doc = $crate::docgen!{@operator_doc $op}]
         $pub type $name = $crate::Operator<
-        {
-                assert!(
-                    $op.len() >= 1 && $op.len() <= 4,
-                    "Operators must be 1-4 ASCII punctuation characters"
-                );
-                let c0 = $crate::operator!(@char_at 0 $op);
-                assert!(c0.is_ascii_punctuation(), "Operator must be ASCII punctuation");
-                c0
-        },
-        {
-                let c1 = $crate::operator!(@char_at 1 $op);
-                assert!(c1 == '\0' || c1.is_ascii_punctuation(), "Operator must be ASCII punctuation");
-                c1
-        },
-        {
-                let c2 = $crate::operator!(@char_at 2 $op);
-                assert!(c2 == '\0' || c2.is_ascii_punctuation(), "Operator must be ASCII punctuation");
-                c2
-        },
-        {
-                let c3 = $crate::operator!(@char_at 3 $op);
-                assert!(c3 == '\0' || c3.is_ascii_punctuation(), "Operator must be ASCII punctuation");
-                c3
-        },
+            { $crate::operator_char($op, 0) },
+            { $crate::operator_char($op, 1) },
+            { $crate::operator_char($op, 2) },
+            { $crate::operator_char($op, 3) },
+            { $crate::operator_rest($op) },
         >;
     };
+}
 
-    // get a single ascii character from a literal string
-    (@char_at $at:literal $op:literal) => {
-       const {
-           concat!($op, "\0\0\0").as_bytes()[$at] as char
-       }
-    }
+/// Define types matching exact literal constants.
+///
+/// `literal!{ pub Name = "literal"; ...}`
+///
+/// * Any number of attributes (`#[...]`), including documentation comments.
+/// * A optional `pub` defines the literal public, default is private.
+/// * `Name` is the name for the struct to be generated.
+/// * `"literal"` is the constant to match. Strings, characters, `true`/`false`, integers,
+///   floats (both may be negative), byte, byte string and C string literals are
+///   supported. Integers and floats must not have a type suffix (`1u8`, `0xffu8`, `1f32`),
+///   suffixed literals fail to compile with a "literal suffixes are not supported" error.
+///
+/// `Name::parse()` will only match a literal with the same value, regardless of how it is
+/// written, `"\x41"` or `r"A"` match a `"A"` constant, `0x10` matches `16`. The constant is
+/// available as `Name::VALUE`. The generated struct implements `Debug`, `Clone`, `Default` and
+/// `Deref` to the underlying literal type ([`LiteralString`], [`LiteralCharacter`], `bool`,
+/// [`LiteralSignedInteger`], `f64`, [`LiteralByte`], [`LiteralByteString`] or
+/// [`LiteralCString`]).
+///
+/// The `unsynn!` macro supports defining literals by using `literal Name = "literal";`, the
+/// `pub` specification has to come before `literal` then.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// literal!{
+///     /// Optional documentation for `Hello`
+///     pub Hello = "hello";
+///     Pi = 3.14;
+///     Yes = true;
+///     MinusOne = -1;
+/// }
+///
+/// let mut tokens = r#" r"hello" 3.14 true -0x1 "world" "#.to_token_iter();
+/// let hello = Hello::parse(&mut tokens).unwrap();
+/// assert_eq!(hello.unescaped(), Hello::VALUE);
+/// assert_tokens_eq!(hello, r#" r"hello" "#);
+/// # let _ = Pi::parse(&mut tokens).unwrap();
+/// # let _ = Yes::parse(&mut tokens).unwrap();
+/// # let _ = MinusOne::parse(&mut tokens).unwrap();
+/// assert!(Hello::parse(&mut tokens).is_err());
+///
+/// assert_tokens_eq!(Pi::default(), "3.14");
+/// ```
+#[macro_export]
+macro_rules! literal {
+    // match a list of literal! defs
+    ($($(#[$attribute:meta])* $pub:vis $name:ident = $lit:literal);*$(;)?) => {
+        $(
+            $crate::literal!(@literal $(#[$attribute])* $pub $name = $lit);
+        )*
+    };
+
+    // the type selecting the literal type
+    (@select $lit:literal) => {
+        $crate::ConstLiteralSelect<{ $crate::const_literal_kind(stringify!($lit)) }>
+    };
+
+    // match a single literal! def
+    (@literal $(#[$attribute:meta])* $pub:vis $name:ident = $lit:literal) => {
+        $(#[$attribute])*
+        #[doc = $crate::docgen!{@literal_doc $lit}]
+        #[derive(Debug, Clone)]
+        $pub struct $name(
+            <$crate::literal!(@select $lit) as $crate::ConstLiteralType>::Literal
+        );
+
+        impl $name {
+            /// The constant this literal matches
+            #[allow(dead_code)]
+            pub const VALUE: <$crate::literal!(@select $lit) as $crate::ConstLiteralType>::Value = $lit;
+
+            /// Deconstructs `self` and gets the underlying literal
+            #[allow(dead_code)]
+            pub fn into_inner(self) -> <$crate::literal!(@select $lit) as $crate::ConstLiteralType>::Literal {
+                self.0
+            }
+        }
+
+        impl $crate::Parser for $name {
+            #[allow(clippy::result_large_err)]
+            fn parser(tokens: &mut $crate::TokenIter) -> $crate::Result<Self> {
+                use $crate::{ConstLiteralType, Parse, RefineErr, ToTokens};
+                let at = tokens.clone().next();
+                <<$crate::literal!(@select $lit) as ConstLiteralType>::Literal as Parse>::parse_with(
+                    tokens,
+                    |literal, tokens| {
+                        if <$crate::literal!(@select $lit)>::matches(&literal, Self::VALUE) {
+                            Ok($name(literal))
+                        } else {
+                            $crate::Error::other::<$name>(
+                                at,
+                                tokens,
+                                format!(
+                                    "literal {} expected, got {}",
+                                    stringify!($lit),
+                                    literal.tokens_to_string()
+                                ),
+                            )
+                        }
+                    },
+                )
+                .refine_err::<Self>()
+            }
+        }
+
+        impl $crate::ToTokens for $name {
+            fn to_tokens(&self, tokens: &mut $crate::TokenStream) {
+                self.0.to_tokens(tokens);
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self(<$crate::literal!(@select $lit) as $crate::ConstLiteralType>::new(Self::VALUE))
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = <$crate::literal!(@select $lit) as $crate::ConstLiteralType>::Literal;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+}
+
+/// Define structs that are parsed from meta argument lists such as `key = value, flag,
+/// nested(...)`.
+///
+/// * Any number of attributes (`#[...]`), including documentation comments.
+/// * A optional `pub` declaration for the struct and its fields.
+/// * Each field name is the key, its type must implement [`MetaField`].
+///
+/// The generated struct implements [`Parser`], [`ToTokens`] and [`MetaField`], the later
+/// makes it usable as nested `key(...)` list in other `meta_args!` structs. Derives such as
+/// `#[derive(Debug)]` are given as attributes like for any other struct. Keys may be
+/// given in any order. Unknown keys, duplicate keys and missing required keys are
+/// errors. Fields of type `bool`, `Option<T>`, [`OrDefault<T, D>`] and [`HiddenState<T>`]
+/// are not required. [`ToTokens`] emits the arguments in field order, flags that are not set,
+/// missing optional values and defaults are left out.
+///
+/// The `unsynn!` macro supports defining meta argument structs by using `meta struct Name
+/// {...}`, the `pub` specification has to come before `meta` then.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// meta_args! {
+///     /// Arguments for `#[my_derive(...)]`
+///     #[derive(Debug)]
+///     pub struct MyArgs {
+///         pub name: LiteralString,
+///         pub skip: bool,
+///         pub rename: Option<LiteralString>,
+///         pub count: OrDefault<u32, ConstInteger<1>>,
+///         pub limits: Option<Limits>,
+///     }
+///
+///     #[derive(Debug)]
+///     pub struct Limits {
+///         pub min: i32,
+///         pub max: i32,
+///     }
+/// }
+///
+/// let mut token_iter = r#"skip, limits(max = 10, min = -10), name = "x""#.to_token_iter();
+/// let args = MyArgs::parse_all(&mut token_iter).unwrap();
+/// assert_eq!(args.name.as_str(), "x");
+/// assert!(args.skip);
+/// assert!(args.rename.is_none());
+/// assert_tokens_eq!(args.count, "1");
+/// assert_eq!(args.limits.as_ref().unwrap().min, -10);
+/// assert_tokens_eq!(args, r#"name = "x", skip, limits(min = -10, max = 10)"#);
+///
+/// // unknown keys are errors
+/// let mut token_iter = r#"name = "x", frob"#.to_token_iter();
+/// assert!(MyArgs::parse_all(&mut token_iter).is_err());
+/// ```
+#[macro_export]
+macro_rules! meta_args {
+    (
+        $(#[$attribute:meta])* $pub:vis struct $name:ident {
+            $($(#[$mattr:meta])* $mpub:vis $field:ident: $type:ty),* $(,)?
+        }
+        $($cont:tt)*
+    ) => {
+        $(#[$attribute])* $pub struct $name {
+            $($(#[$mattr])* $mpub $field: $type),*
+        }
+
+        impl $name {
+            /// Extracts `Self` from parsed meta arguments.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error on unknown, duplicate or missing arguments or when a
+            /// argument can not be converted.
+            #[allow(dead_code)]
+            pub fn from_meta_args(args: &$crate::MetaArgs) -> $crate::Result<Self> {
+                <Self as $crate::Parse>::parse_all(&mut $crate::ToTokens::to_token_iter(args))
+            }
+        }
+
+        impl $crate::Parser for $name {
+            fn parser(tokens: &mut $crate::TokenIter) -> $crate::Result<Self> {
+                let start = tokens.clone();
+                let entries =
+                    $crate::meta_parse_args::<Self>(tokens, &[$(stringify!($field)),*])?;
+                Ok(Self {
+                    $($field: $crate::meta_field::<Self, $type>(&entries, &start, stringify!($field))?),*
+                })
+            }
+        }
+
+        impl $crate::ToTokens for $name {
+            fn to_tokens(&self, tokens: &mut $crate::TokenStream) {
+                $crate::meta_to_tokens(
+                    [$($crate::MetaField::to_meta(&self.$field, stringify!($field))),*],
+                    tokens,
+                );
+            }
+        }
+
+        impl $crate::MetaField for $name {
+            fn from_meta(meta: &$crate::Meta) -> $crate::Result<Self> {
+                match meta {
+                    $crate::Meta::List(list) => Self::from_meta_args(&list.nested.content),
+                    _ => meta.error(format!("expected `{}(...)`", meta.name().as_str())),
+                }
+            }
+
+            fn to_meta(&self, key: &str) -> Option<$crate::TokenStream> {
+                let mut tokens = $crate::ToTokens::into_token_stream(
+                    $crate::Ident::new(key, $crate::Span::call_site())
+                );
+                $crate::ToTokens::to_tokens(
+                    &$crate::ParenthesisGroupContaining::new($crate::ToTokens::to_token_stream(self)),
+                    &mut tokens,
+                );
+                Some(tokens)
+            }
+        }
+
+        $crate::meta_args!{$($cont)*}
+    };
+    () => {};
 }
 
 // The documentation generator
@@ -1316,9 +1763,18 @@ macro_rules! docgen {
     (@keyword_doc $sub:path) => {
         concat!("[`", stringify!($sub), "`], ")
     };
+    (@operator_header) => {
+        "Matches the longest of: "
+    };
+    (@operator_header !) => {
+        "Matches any operator but: "
+    };
     (@operator_doc $op:literal) => {
         concat!("`", $op, "`")
     };
+    (@literal_doc $lit:literal) => {
+        concat!("Matches: `", stringify!($lit), "`")
+    };
 }
 
 /// unsynn provides its own `quote!{}` macro that translates tokens into a `TokenStream` while
@@ -0,0 +1,365 @@
+//! Most proc-macros take arguments in the form of `key = value, flag, nested(...)` lists. This
+//! module provides the [`Meta`] grammar for such lists and the [`MetaField`] trait to
+//! extract typed values from them. Together with the [`meta_args!`] macro (or `meta struct`
+//! within [`unsynn!`]) a user defined struct can be parsed from meta arguments. Unknown,
+//! duplicate and missing keys are reported as errors. Defaults are given by `Option<T>`,
+//! [`OrDefault<T, D>`] and [`HiddenState<T>`] fields.
+
+#![allow(clippy::module_name_repetitions)]
+
+#[allow(clippy::wildcard_imports)]
+use crate::*;
+
+/// The value of a `key = value` pair. This is anything up to the next comma.
+pub type MetaValue = IntoTokenStream<Many<Cons<Except<Comma>, TokenTree>>>;
+
+/// A `name(...)` list containing nested meta arguments.
+#[derive(Debug, Clone)]
+pub struct MetaList {
+    /// The name of the list
+    pub name: CachedIdent,
+    /// The nested arguments
+    pub nested: ParenthesisGroupContaining<MetaArgs>,
+}
+
+impl Parser for MetaList {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            name: CachedIdent::parser(tokens)?,
+            nested: ParenthesisGroupContaining::parser(tokens)?,
+        })
+    }
+}
+
+impl ToTokens for MetaList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.nested.to_tokens(tokens);
+    }
+}
+
+/// A `name = value` pair.
+#[derive(Debug, Clone)]
+pub struct MetaNameValue {
+    /// The name/key
+    pub name: CachedIdent,
+    /// The `=`
+    pub assign: Assign,
+    /// Tokens up to the next comma
+    pub value: MetaValue,
+}
+
+impl Parser for MetaNameValue {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            name: CachedIdent::parser(tokens)?,
+            assign: Assign::parser(tokens)?,
+            value: MetaValue::parser(tokens)?,
+        })
+    }
+}
+
+impl ToTokens for MetaNameValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.assign.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+/// A single entry in a meta argument list.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#"name = "x", skip, nested(a = 1)"#.to_token_iter();
+///
+/// let args = MetaArgs::parse_all(&mut token_iter).unwrap();
+/// assert!(matches!(args[0].value, Meta::NameValue(_)));
+/// assert!(matches!(args[1].value, Meta::Path(_)));
+/// assert!(matches!(args[2].value, Meta::List(_)));
+/// assert_eq!(args[2].value.name().as_str(), "nested");
+/// ```
+#[derive(Debug, Clone)]
+pub enum Meta {
+    /// `name(...)`
+    List(MetaList),
+    /// `name = value`
+    NameValue(MetaNameValue),
+    /// a lone `name`, used for flags
+    Path(CachedIdent),
+}
+
+impl Parser for Meta {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(list) = err.upgrade(MetaList::parse(tokens)) {
+            return Ok(Meta::List(list));
+        }
+        if let Ok(name_value) = err.upgrade(MetaNameValue::parse(tokens)) {
+            return Ok(Meta::NameValue(name_value));
+        }
+        if let Ok(path) = err.upgrade(CachedIdent::parse(tokens)) {
+            return Ok(Meta::Path(path));
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for Meta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Meta::List(list) => list.to_tokens(tokens),
+            Meta::NameValue(name_value) => name_value.to_tokens(tokens),
+            Meta::Path(path) => path.to_tokens(tokens),
+        }
+    }
+}
+
+/// A comma delimited list of [`Meta`] entries.
+pub type MetaArgs = CommaDelimitedVec<Meta>;
+
+impl Meta {
+    /// Returns the name (key) of this entry.
+    #[must_use]
+    pub const fn name(&self) -> &CachedIdent {
+        match self {
+            Meta::List(list) => &list.name,
+            Meta::NameValue(name_value) => &name_value.name,
+            Meta::Path(name) => name,
+        }
+    }
+
+    /// Returns a error about this entry.
+    ///
+    /// # Errors
+    ///
+    /// Always returns a `ErrorKind::Other` error pointing at the name of this entry.
+    pub fn error<T>(&self, reason: String) -> Result<T> {
+        let tokens = self.to_token_iter();
+        Error::other(Some(self.name().clone().into()), &tokens, reason)
+    }
+
+    /// Parses the value of a `name = value` entry exhaustively as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when this is not a `name = value` entry or the value can not be parsed
+    /// as `T`.
+    pub fn parse_value<T: Parse>(&self) -> Result<T> {
+        match self {
+            Meta::NameValue(name_value) => T::parse_all(&mut name_value.value.to_token_iter()),
+            _ => self.error(format!("expected `{} = value`", self.name().as_str())),
+        }
+    }
+}
+
+/// Types that can be extracted from a [`Meta`] entry. This is implemented for the literal
+/// types, rust integer and float types, `char`, [`Ident`] and [`CachedIdent`] which are parsed
+/// from `key = value` entries. `bool` is a flag that is `true` when the key is present (or
+/// `key = true`) and `false` when it is missing. Structs defined by [`meta_args!`] are
+/// parsed from `key(...)` lists.
+///
+/// `Option<T>`, [`OrDefault<T, D>`] and [`HiddenState<T>`] provide defaults for missing
+/// keys, any other missing key is an error.
+pub trait MetaField: Sized {
+    /// Extracts `Self` from a entry with a matching key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the entry has the wrong shape or its value can not be parsed.
+    fn from_meta(meta: &Meta) -> Result<Self>;
+
+    /// The value used when the key is missing, `None` when the key is required.
+    #[must_use]
+    fn from_missing() -> Option<Self> {
+        None
+    }
+
+    /// Emits `self` as meta entry with the given `key`. Returns `None` when nothing needs to be
+    /// emitted.
+    fn to_meta(&self, key: &str) -> Option<TokenStream>;
+}
+
+macro_rules! impl_meta_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl MetaField for $ty {
+                fn from_meta(meta: &Meta) -> Result<Self> {
+                    meta.parse_value()
+                }
+
+                fn to_meta(&self, key: &str) -> Option<TokenStream> {
+                    let mut tokens = Ident::new(key, Span::call_site()).into_token_stream();
+                    Assign::new().to_tokens(&mut tokens);
+                    self.to_tokens(&mut tokens);
+                    Some(tokens)
+                }
+            }
+        )*
+    };
+}
+
+impl_meta_value! {
+    LiteralString, LiteralInteger, LiteralSignedInteger, LiteralFloat, LiteralCharacter,
+    LiteralByte, LiteralByteString, LiteralCString, LiteralKind, Ident, CachedIdent, Literal,
+    char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+}
+
+/// A flag, `true` when present as `key` or `key = true`, `false` when missing.
+impl MetaField for bool {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(true),
+            Meta::NameValue(_) => meta.parse_value(),
+            Meta::List(_) => meta.error(format!("expected flag `{}`", meta.name().as_str())),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(false)
+    }
+
+    fn to_meta(&self, key: &str) -> Option<TokenStream> {
+        self.then(|| Ident::new(key, Span::call_site()).into_token_stream())
+    }
+}
+
+/// Optional key, `None` when missing.
+impl<T: MetaField> MetaField for Option<T> {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        T::from_meta(meta).map(Some)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+
+    fn to_meta(&self, key: &str) -> Option<TokenStream> {
+        self.as_ref().and_then(|value| value.to_meta(key))
+    }
+}
+
+/// Key with a default value `D` used when missing. The default is not emitted.
+impl<T: MetaField, D: Default> MetaField for OrDefault<T, D> {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        T::from_meta(meta).map(Either::First)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(Either::Second(Insert(D::default())))
+    }
+
+    fn to_meta(&self, key: &str) -> Option<TokenStream> {
+        match self {
+            Either::First(value) => value.to_meta(key),
+            _ => None,
+        }
+    }
+}
+
+/// Not a argument, always default constructed. Using it as key is an error.
+impl<T: Default> MetaField for HiddenState<T> {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        meta.error(format!("unknown argument `{}`", meta.name().as_str()))
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(HiddenState::default())
+    }
+
+    fn to_meta(&self, _key: &str) -> Option<TokenStream> {
+        None
+    }
+}
+
+// Implementation details for the `meta_args!` macro.
+
+/// Parses the arguments of a `meta_args!` struct and checks that only `keys` are given, each
+/// at most once. The entries are returned together with the position they were parsed at.
+#[doc(hidden)]
+#[allow(clippy::missing_errors_doc)]
+pub fn meta_parse_args<'a, S>(
+    tokens: &mut TokenIter<'a>,
+    keys: &[&str],
+) -> Result<Vec<(TokenIter<'a>, Meta)>> {
+    let mut entries: Vec<(TokenIter<'a>, Meta)> = Vec::new();
+    loop {
+        let at = tokens.clone();
+        let Ok(delimited) = Delimited::<Meta, Comma>::parse(tokens) else {
+            break;
+        };
+        let name = delimited.value.name().as_str();
+        if !keys.contains(&name) {
+            return Error::other(
+                at.clone().next(),
+                &at,
+                format!(
+                    "unknown argument `{name}`, expected one of: {}",
+                    keys.join(", ")
+                ),
+            )
+            .refine_err::<S>();
+        }
+        if entries.iter().any(|(_, meta)| meta.name().as_str() == name) {
+            return Error::other(
+                at.clone().next(),
+                &at,
+                format!("duplicate argument `{name}`"),
+            )
+            .refine_err::<S>();
+        }
+        let done = delimited.delimiter.is_none();
+        entries.push((at, delimited.value));
+        if done {
+            break;
+        }
+    }
+    Ok(entries)
+}
+
+/// Extracts the field `key` from `entries`. Missing arguments are reported at `start`, the
+/// beginning of the argument list.
+#[doc(hidden)]
+#[allow(clippy::missing_errors_doc)]
+pub fn meta_field<S, T: MetaField>(
+    entries: &[(TokenIter, Meta)],
+    start: &TokenIter,
+    key: &str,
+) -> Result<T> {
+    match entries.iter().find(|(_, meta)| meta.name().as_str() == key) {
+        Some((at, meta)) => T::from_meta(meta)
+            .map_err(|mut err| {
+                // the entry was converted from its own tokens, report where it was parsed
+                err.relocate(at);
+                err
+            })
+            .refine_err::<S>(),
+        None => T::from_missing().map_or_else(
+            || {
+                Error::other(
+                    start.clone().next(),
+                    start,
+                    format!("missing argument `{key}`"),
+                )
+                .refine_err::<S>()
+            },
+            Ok,
+        ),
+    }
+}
+
+/// Emits the `metas` comma delimited.
+#[doc(hidden)]
+pub fn meta_to_tokens(
+    metas: impl IntoIterator<Item = Option<TokenStream>>,
+    tokens: &mut TokenStream,
+) {
+    for (n, meta) in metas.into_iter().flatten().enumerate() {
+        if n > 0 {
+            Comma::new().to_tokens(tokens);
+        }
+        tokens.extend(meta);
+    }
+}
@@ -1,103 +1,448 @@
 //! Combined punctuation tokens are represented by [`Operator`]. The [`crate::operator!`]
-//! macro can be used to define custom operators.
+//! macro can be used to define custom operators of up to [`OPERATOR_MAX_LEN`] characters.
+//! [`DynOperator`] matches operators of any length that are only known at runtime.
 
 use crate::{
-    Parser, Punct, PunctAny, PunctJoint, Result, Spacing, ToTokens, TokenIter, TokenStream,
+    Error, KeywordGroup, Parser, Punct, RefineErr, Result, Spacing, ToTokens, TokenIter,
+    TokenStream, TokenTree, Transaction,
 };
 
-/// Operators made from up to four ASCII punctuation characters. Unused characters default to `\0`.
-/// Custom operators can be defined with the [`crate::operator!`] macro. All but the last character are
-/// [`Spacing::Joint`]. Attention must be payed when operators have the same prefix, the shorter
-/// ones need to be tried first.
+/// Operators made from ASCII punctuation characters. Unused characters default to `\0`. The
+/// first four characters are given as `char`, longer operators of up to
+/// [`OPERATOR_MAX_LEN`] characters pack the remaining characters into `REST`, one byte per
+/// character starting at the least significant byte. These are best defined with the
+/// [`crate::operator!`] macro which defines its operators as aliases of `Operator`. All but
+/// the last character are [`Spacing::Joint`]. Attention must be paid when operators have the
+/// same prefix, the shorter ones need to be tried first.
 #[derive(Default, Copy, Clone, PartialEq, Eq)]
 pub struct Operator<
     const C1: char,
     const C2: char = '\0',
     const C3: char = '\0',
     const C4: char = '\0',
+    const REST: u128 = 0,
 >;
 
-impl<const C1: char, const C2: char, const C3: char, const C4: char> Operator<C1, C2, C3, C4> {
+/// The maximum number of characters of an [`Operator`].
+pub const OPERATOR_MAX_LEN: usize = 4 + std::mem::size_of::<u128>();
+
+impl<const C1: char, const C2: char, const C3: char, const C4: char, const REST: u128>
+    Operator<C1, C2, C3, C4, REST>
+{
+    // `&` of the initializer makes this a `'static` reference
+    const BYTES: &'static [u8; OPERATOR_MAX_LEN] = &operator_bytes([C1, C2, C3, C4], REST);
+
+    /// The characters of this operator.
+    pub const OPERATOR: &'static str = {
+        let bytes = Self::BYTES;
+        let mut len = 0;
+        while len < OPERATOR_MAX_LEN && bytes[len] != 0 {
+            len += 1;
+        }
+        let (operator, rest) = bytes.split_at(len);
+        let mut gap = false;
+        let mut i = 0;
+        while i < rest.len() {
+            gap |= rest[i] != 0;
+            i += 1;
+        }
+        match std::str::from_utf8(operator) {
+            Ok(operator) if !gap && is_operator(operator) => operator,
+            _ => panic!("Operators must be one or more ASCII punctuation characters"),
+        }
+    };
+
     /// Create a new `Operator` object.
     #[must_use]
     pub const fn new() -> Self {
         const {
-            assert!(C1.is_ascii_punctuation());
-            assert!(C2 == '\0' || C2.is_ascii_punctuation());
-            assert!(C3 == '\0' || C3.is_ascii_punctuation());
-            assert!(C4 == '\0' || C4.is_ascii_punctuation());
+            assert!(!Self::OPERATOR.is_empty());
         }
         Self
     }
+
+    /// Gets the characters of this operator.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        Self::OPERATOR
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    pub const fn operators() -> &'static KeywordGroup {
+        const { &KeywordGroup::Keyword(Self::OPERATOR) }
+    }
 }
 
-impl<const C1: char, const C2: char, const C3: char, const C4: char> Parser
-    for Operator<C1, C2, C3, C4>
+impl<const C1: char, const C2: char, const C3: char, const C4: char, const REST: u128> Parser
+    for Operator<C1, C2, C3, C4, REST>
 {
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
-        if C2 == '\0' {
-            PunctAny::<C1>::parser(tokens)?;
-            Ok(Self)
-        } else {
-            PunctJoint::<C1>::parser(tokens)?;
-            if C3 == '\0' {
-                PunctAny::<C2>::parser(tokens)?;
-                Ok(Self)
-            } else {
-                PunctJoint::<C2>::parser(tokens)?;
-                if C4 == '\0' {
-                    PunctAny::<C3>::parser(tokens)?;
-                    Ok(Self)
-                } else {
-                    PunctJoint::<C3>::parser(tokens)?;
-                    PunctAny::<C4>::parser(tokens)?;
-                    Ok(Self)
-                }
-            }
-        }
+        parse_operator::<Self>(tokens, Self::OPERATOR, false)?;
+        Ok(Self)
     }
 }
 
-impl<const C1: char, const C2: char, const C3: char, const C4: char> ToTokens
-    for Operator<C1, C2, C3, C4>
+impl<const C1: char, const C2: char, const C3: char, const C4: char, const REST: u128> ToTokens
+    for Operator<C1, C2, C3, C4, REST>
 {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        // Make the spacing `Joint` when the next character is not a space.
-        const fn spacing(c: char) -> Spacing {
-            if c == '\0' {
-                Spacing::Alone
-            } else {
-                Spacing::Joint
-            }
+        operator_to_tokens(Self::OPERATOR, tokens);
+    }
+}
+
+#[mutants::skip]
+impl<const C1: char, const C2: char, const C3: char, const C4: char, const REST: u128>
+    std::fmt::Debug for Operator<C1, C2, C3, C4, REST>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Operator<'{}'>", Self::OPERATOR)
+    }
+}
+
+/// Operators that can be matched regardless of the [`Spacing`] between their characters.
+/// This is implemented for [`Operator`], [`DynOperator`] is matched with
+/// [`DynOperator::parse_exact_any_spacing()`]. The [`crate::operator!`] macro implements it
+/// for single operators and operator groups that are not negated.
+pub trait SpacingInsensitive: Sized {
+    /// Parses `Self` while ignoring whether its characters are joint or alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the next tokens do not match the operator.
+    fn parse_any_spacing(tokens: &mut TokenIter) -> Result<Self>;
+}
+
+impl<const C1: char, const C2: char, const C3: char, const C4: char, const REST: u128>
+    SpacingInsensitive for Operator<C1, C2, C3, C4, REST>
+{
+    fn parse_any_spacing(tokens: &mut TokenIter) -> Result<Self> {
+        parse_operator::<Self>(tokens, Self::OPERATOR, true)?;
+        Ok(Self)
+    }
+}
+
+/// Matches the operator `O` regardless of the [`Spacing`] between its characters. This is
+/// meant for input produced by other macros which may emit operators as separate
+/// punctuation. The operator is emitted in its canonical form with joint characters.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "- > >> =".to_token_iter();
+/// assert!(RArrow::parse(&mut token_iter.clone()).is_err());
+///
+/// let arrow = AnySpacing::<RArrow>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(arrow, "->");
+///
+/// let shift = AnySpacing::<ShrEq>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(shift, ">>=");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AnySpacing<O>(pub O);
+
+impl<O: SpacingInsensitive> Parser for AnySpacing<O> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        O::parse_any_spacing(tokens).map(Self)
+    }
+}
+
+impl<O: ToTokens> ToTokens for AnySpacing<O> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<O> std::ops::Deref for AnySpacing<O> {
+    type Target = O;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// Implementation details only exposed because the operator macro needs them
+
+/// Returns `true` when `chars` is a valid operator, one or more ASCII punctuation characters.
+#[doc(hidden)]
+#[must_use]
+pub const fn is_operator(chars: &str) -> bool {
+    let bytes = chars.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_punctuation() {
+            return false;
         }
+        i += 1;
+    }
+    !bytes.is_empty()
+}
 
-        Punct::new(C1, spacing(C2)).to_tokens(tokens);
-        if C2 != '\0' {
-            Punct::new(C2, spacing(C3)).to_tokens(tokens);
-            if C3 != '\0' {
-                Punct::new(C3, spacing(C4)).to_tokens(tokens);
-                if C4 != '\0' {
-                    Punct::new(C4, Spacing::Alone).to_tokens(tokens);
+/// Gets the character at `index` of the operator `chars` or `\0` past its end. Used by the
+/// [`crate::operator!`] macro to define `Operator` aliases, this fails to compile for
+/// invalid operators.
+#[doc(hidden)]
+#[must_use]
+pub const fn operator_char(chars: &str, index: usize) -> char {
+    assert!(
+        is_operator(chars),
+        "Operators must be one or more ASCII punctuation characters"
+    );
+    assert!(
+        chars.len() <= OPERATOR_MAX_LEN,
+        "Operators must not be longer than 20 characters"
+    );
+    let bytes = chars.as_bytes();
+    if index < bytes.len() {
+        bytes[index] as char
+    } else {
+        '\0'
+    }
+}
+
+/// Packs the characters of `chars` past the fourth into the `REST` parameter of [`Operator`].
+#[doc(hidden)]
+#[must_use]
+pub const fn operator_rest(chars: &str) -> u128 {
+    let bytes = chars.as_bytes();
+    let mut rest = 0;
+    let mut i = bytes.len();
+    while i > 4 {
+        i -= 1;
+        rest = rest << 8 | bytes[i] as u128;
+    }
+    rest
+}
+
+/// Unpacks the characters of an [`Operator`], see [`operator_rest()`].
+#[allow(clippy::cast_possible_truncation)]
+const fn operator_bytes(chars: [char; 4], mut rest: u128) -> [u8; OPERATOR_MAX_LEN] {
+    let mut bytes = [0; OPERATOR_MAX_LEN];
+    let mut i = 0;
+    while i < chars.len() {
+        bytes[i] = chars[i] as u8;
+        i += 1;
+    }
+    while rest != 0 {
+        bytes[i] = rest as u8;
+        rest >>= 8;
+        i += 1;
+    }
+    bytes
+}
+
+/// Parses the punctuation sequence `chars`, all but the last character must be
+/// [`Spacing::Joint`] unless `any_spacing` is set. Errors are reported for the type `T`.
+#[doc(hidden)]
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_operator<T>(tokens: &mut TokenIter, chars: &str, any_spacing: bool) -> Result<()> {
+    parse_chars::<T>(tokens, chars.chars(), any_spacing)
+}
+
+fn parse_chars<T>(
+    tokens: &mut TokenIter,
+    chars: impl Iterator<Item = char>,
+    any_spacing: bool,
+) -> Result<()> {
+    let mut chars = chars.peekable();
+    while let Some(c) = chars.next() {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct))
+                if punct.as_char() == c
+                    && (any_spacing
+                        || chars.peek().is_none()
+                        || punct.spacing() == Spacing::Joint) => {}
+            at => return Error::unexpected_token::<T>(at, tokens).map(|_| ()),
+        }
+    }
+    Ok(())
+}
+
+/// Parses the longest matching operator from `operators`, these must be sorted longest
+/// first. Spacing is handled like in [`parse_operator()`]. Errors are reported for the type `T`.
+#[doc(hidden)]
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_operator_group<T>(
+    tokens: &mut TokenIter,
+    operators: &[&'static str],
+    any_spacing: bool,
+) -> Result<&'static str> {
+    for operator in operators {
+        let mut attempt = tokens.clone();
+        if parse_operator::<T>(&mut attempt, operator, any_spacing).is_ok() {
+            *tokens = attempt;
+            return Ok(operator);
+        }
+    }
+    let at = tokens.next();
+    Error::other(at, tokens, format!("expected one of {operators:?}")).refine_err::<T>()
+}
+
+/// Parses any operator that is not in `operators`. Errors are reported for the type `T`.
+#[doc(hidden)]
+#[allow(clippy::missing_errors_doc)]
+pub fn parse_not_operator_group<T>(
+    tokens: &mut TokenIter,
+    operators: &[&'static str],
+) -> Result<DynOperator> {
+    let at = tokens.clone().next();
+    let operator = DynOperator::parser(tokens).refine_err::<T>()?;
+    if operators.contains(&operator.as_str()) {
+        Error::other(
+            at,
+            tokens,
+            format!(
+                "expected any operator but {operators:?}, got {:?}",
+                operator.as_str()
+            ),
+        )
+        .refine_err::<T>()
+    } else {
+        Ok(operator)
+    }
+}
+
+/// Emits the punctuation sequence `chars`, all but the last character are
+/// [`Spacing::Joint`].
+#[doc(hidden)]
+pub fn operator_to_tokens(chars: &str, tokens: &mut TokenStream) {
+    let mut chars = chars.chars().peekable();
+    while let Some(c) = chars.next() {
+        let spacing = if chars.peek().is_some() {
+            Spacing::Joint
+        } else {
+            Spacing::Alone
+        };
+        Punct::new(c, spacing).to_tokens(tokens);
+    }
+}
+
+/// An operator that is only known at runtime, for example when it comes from a configuration.
+/// It is created from a `&str` of ASCII punctuation characters with [`DynOperator::new()`],
+/// [`DynOperator::parse_exact()`] matches exactly this operator with the same spacing rules
+/// as operators defined by [`crate::operator!`]. When used as [`Parser`] it matches any
+/// sequence of punctuation characters that are joint.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "<===> a <===> <=>".to_token_iter();
+///
+/// let arrow = DynOperator::new("<===>");
+/// let parsed = arrow.parse_exact(&mut token_iter).unwrap();
+/// assert_eq!(parsed, arrow);
+/// assert_tokens_eq!(parsed, "<===>");
+/// # let _ = Ident::parse(&mut token_iter).unwrap();
+///
+/// // any operator
+/// let any = DynOperator::parse(&mut token_iter).unwrap();
+/// assert_eq!(any, "<===>");
+/// assert!(arrow.parse_exact(&mut token_iter).is_err());
+/// assert_eq!(DynOperator::parse(&mut token_iter).unwrap().as_str(), "<=>");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DynOperator(String);
+
+impl DynOperator {
+    /// Creates a new `DynOperator` from its characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `chars` is empty or contains characters that are not ASCII punctuation.
+    #[must_use]
+    pub fn new(chars: &str) -> Self {
+        assert!(
+            is_operator(chars),
+            "'{chars}' is not a valid operator, operators must be ASCII punctuation"
+        );
+        Self(chars.to_string())
+    }
+
+    /// Gets the characters of the operator.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses exactly this operator. This is transactional like [`Parse::parse()`],
+    /// on failure the tokens are not consumed.
+    ///
+    /// [`Parse::parse()`]: crate::Parse::parse
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the next tokens are not this operator.
+    pub fn parse_exact(&self, tokens: &mut TokenIter) -> Result<Self> {
+        tokens.transaction(|tokens| {
+            parse_operator::<Self>(tokens, &self.0, false)?;
+            Ok(self.clone())
+        })
+    }
+
+    /// Parses exactly this operator while ignoring whether its characters are joint or
+    /// alone, see [`AnySpacing`]. This is transactional like [`DynOperator::parse_exact()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the next tokens are not this operator.
+    pub fn parse_exact_any_spacing(&self, tokens: &mut TokenIter) -> Result<Self> {
+        tokens.transaction(|tokens| {
+            parse_operator::<Self>(tokens, &self.0, true)?;
+            Ok(self.clone())
+        })
+    }
+}
+
+impl Parser for DynOperator {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut chars = String::new();
+        loop {
+            match tokens.next() {
+                Some(TokenTree::Punct(punct)) => {
+                    chars.push(punct.as_char());
+                    if punct.spacing() == Spacing::Alone
+                        || !matches!(tokens.clone().next(), Some(TokenTree::Punct(_)))
+                    {
+                        return Ok(Self(chars));
+                    }
                 }
+                at => return Error::unexpected_token(at, tokens),
             }
         }
     }
 }
 
+impl ToTokens for DynOperator {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        operator_to_tokens(&self.0, tokens);
+    }
+}
+
+impl AsRef<str> for DynOperator {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for DynOperator {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for DynOperator {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
 #[mutants::skip]
-impl<const C1: char, const C2: char, const C3: char, const C4: char> std::fmt::Debug
-    for Operator<C1, C2, C3, C4>
-{
+impl std::fmt::Debug for DynOperator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if C4 != '\0' {
-            write!(f, "Operator<'{C1}{C2}{C3}{C4}'>")
-        } else if C3 != '\0' {
-            write!(f, "Operator<'{C1}{C2}{C3}'>")
-        } else if C2 != '\0' {
-            write!(f, "Operator<'{C1}{C2}'>")
-        } else {
-            write!(f, "Operator<'{C1}'>")
-        }
+        write!(f, "DynOperator<'{}'>", self.0)
     }
 }
 
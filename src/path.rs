@@ -0,0 +1,524 @@
+//! Paths and type expressions. These cover the paths and types commonly found in macro input,
+//! `a::b::C<T, U>`, `&'a mut [T]`, tuples, arrays, trait objects and function pointers. They are deliberately lightweight, the
+//! complete rust type grammar is left to the `unsynn-rust` crate.
+//!
+//! Paths are stored as [`PathSepDelimitedVec`] of their segments. Unlike parsing a
+//! `PathSepDelimitedVec` directly, a path never takes a trailing `::`.
+#![allow(clippy::module_name_repetitions)]
+
+#[allow(clippy::wildcard_imports)]
+use crate::*;
+
+// `keyword!` can't be used within unsynn, it is only a documentation stub when building the docs
+macro_rules! rust_keyword {
+    ($($name:ident = $str:literal;)*) => {
+        $(
+            #[doc = concat!("The `", $str, "` keyword")]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub struct $name(CachedIdent);
+
+            impl Parser for $name {
+                fn parser(tokens: &mut TokenIter) -> Result<Self> {
+                    let at = tokens.clone().next();
+                    match CachedIdent::parser(tokens) {
+                        Ok(ident) if ident == $str => Ok(Self(ident)),
+                        _ => Error::unexpected_token(at, tokens),
+                    }
+                }
+            }
+
+            impl ToTokens for $name {
+                fn to_tokens(&self, tokens: &mut TokenStream) {
+                    self.0.to_tokens(tokens);
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self(CachedIdent::new($str))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use rust_keyword;
+
+rust_keyword! {
+    Mut = "mut";
+    Const = "const";
+    Dyn = "dyn";
+    Impl = "impl";
+    // not `Fn`, that would shadow the prelude trait for glob imports of unsynn
+    FnKw = "fn";
+}
+
+/// Parses one or more `T` delimited by `::` without consuming a trailing `::`.
+fn parse_path<T: Parse>(tokens: &mut TokenIter) -> Result<PathSepDelimitedVec<T>> {
+    let mut segments = vec![Delimited {
+        value: T::parser(tokens)?,
+        delimiter: None,
+    }];
+    while let Ok(Cons {
+        first: path_sep,
+        second: value,
+        ..
+    }) = Cons::<PathSep, T>::parse(tokens)
+    {
+        if let Some(last) = segments.last_mut() {
+            last.delimiter = Some(path_sep);
+        }
+        segments.push(Delimited {
+            value,
+            delimiter: None,
+        });
+    }
+    Ok(DelimitedVec(segments))
+}
+
+/// A path made of identifiers only, like `std::fmt::Debug` or `::core::mem`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "::std::fmt::Debug ::<T>".to_token_iter();
+///
+/// let path = SimplePath::parse(&mut token_iter).unwrap();
+/// assert!(path.leading.is_some());
+/// assert_eq!(path.last_segment().unwrap().as_str(), "Debug");
+/// assert_tokens_eq!(path, "::std::fmt::Debug");
+/// // the trailing `::` is not part of the path
+/// assert_tokens_eq!(token_iter, "::<T>");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SimplePath {
+    /// The leading `::` of absolute paths
+    pub leading: Option<PathSep>,
+    /// The path segments
+    pub segments: PathSepDelimitedVec<CachedIdent>,
+}
+
+impl SimplePath {
+    /// Gets the last segment, `None` when the path is empty.
+    #[must_use]
+    pub fn last_segment(&self) -> Option<&CachedIdent> {
+        self.segments.values().last()
+    }
+
+    /// Returns `true` when the path is the single identifier `name`.
+    #[must_use]
+    pub fn is_ident(&self, name: &str) -> bool {
+        self.leading.is_none() && self.segments.len() == 1 && self.segments[0].value == name
+    }
+}
+
+impl Parser for SimplePath {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            leading: Option::<PathSep>::parser(tokens).refine_err::<Self>()?,
+            segments: parse_path(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl ToTokens for SimplePath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.leading.to_tokens(tokens);
+        self.segments.to_tokens(tokens);
+    }
+}
+
+/// A single generic argument.
+#[derive(Debug, Clone)]
+pub enum GenericArg {
+    /// A lifetime, `'a`
+    Lifetime(Lifetime),
+    /// An associated type binding, `Item = T`
+    Binding {
+        /// The name of the associated type
+        ident: CachedIdent,
+        /// The `=`
+        assign: Assign,
+        /// The bound type
+        ty: TypeExpr,
+    },
+    /// A type
+    Type(TypeExpr),
+    /// A literal const argument, `3`
+    Literal(LiteralKind),
+    /// A block const argument, `{ N + 1 }`
+    Block(BraceGroup),
+}
+
+impl Parser for GenericArg {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(lifetime) = err.upgrade(Lifetime::parse(tokens)) {
+            return Ok(Self::Lifetime(lifetime));
+        }
+        if let Ok(binding) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Binding {
+                ident: CachedIdent::parser(tokens)?,
+                assign: Assign::parser(tokens)?,
+                ty: TypeExpr::parser(tokens)?,
+            })
+        })) {
+            return Ok(binding);
+        }
+        if let Ok(ty) = err.upgrade(TypeExpr::parse(tokens)) {
+            return Ok(Self::Type(ty));
+        }
+        if let Ok(literal) = err.upgrade(LiteralKind::parse(tokens)) {
+            return Ok(Self::Literal(literal));
+        }
+        if let Ok(block) = err.upgrade(BraceGroup::parse(tokens)) {
+            return Ok(Self::Block(block));
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for GenericArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime(lifetime) => lifetime.to_tokens(tokens),
+            Self::Binding { ident, assign, ty } => {
+                ident.to_tokens(tokens);
+                assign.to_tokens(tokens);
+                ty.to_tokens(tokens);
+            }
+            Self::Type(ty) => ty.to_tokens(tokens),
+            Self::Literal(literal) => literal.to_tokens(tokens),
+            Self::Block(block) => block.to_tokens(tokens),
+        }
+    }
+}
+
+/// Generic arguments of a path segment, `<T, 'a>` or with turbofish `::<T>`.
+#[derive(Debug, Clone)]
+pub struct GenericArgs {
+    /// The `::` of a turbofish
+    pub turbofish: Option<PathSep>,
+    /// The `<`
+    pub lt: Lt,
+    /// The arguments
+    pub args: CommaDelimitedVec<GenericArg>,
+    /// The `>`
+    pub gt: Gt,
+}
+
+impl Parser for GenericArgs {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            turbofish: Option::<PathSep>::parser(tokens).refine_err::<Self>()?,
+            lt: Lt::parser(tokens).refine_err::<Self>()?,
+            args: CommaDelimitedVec::parser(tokens).refine_err::<Self>()?,
+            gt: Gt::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl ToTokens for GenericArgs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.turbofish.to_tokens(tokens);
+        self.lt.to_tokens(tokens);
+        self.args.to_tokens(tokens);
+        self.gt.to_tokens(tokens);
+    }
+}
+
+/// A path segment with optional generic arguments, `Vec<T>`.
+#[derive(Debug, Clone)]
+pub struct PathSegment {
+    /// The name of the segment
+    pub ident: CachedIdent,
+    /// The generic arguments
+    pub generics: Option<GenericArgs>,
+}
+
+impl Parser for PathSegment {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            ident: CachedIdent::parser(tokens).refine_err::<Self>()?,
+            generics: Option::<GenericArgs>::parser(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl ToTokens for PathSegment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+        self.generics.to_tokens(tokens);
+    }
+}
+
+/// A path where segments may have generic arguments, like `std::collections::HashMap<K, V>`
+/// or `Vec::<T>::new`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "a::b::C<T, Vec<U>>".to_token_iter();
+///
+/// let path = GenericPath::parse(&mut token_iter).unwrap();
+/// let last = path.last_segment().unwrap();
+/// assert_eq!(last.ident, "C");
+/// assert_eq!(last.generics.as_ref().unwrap().args.len(), 2);
+/// // the closing `>>` is emitted as two separate `>`
+/// assert_tokens_eq!(path, "a::b::C<T, Vec<U> >");
+/// ```
+#[derive(Debug, Clone)]
+pub struct GenericPath {
+    /// The leading `::` of absolute paths
+    pub leading: Option<PathSep>,
+    /// The path segments
+    pub segments: PathSepDelimitedVec<PathSegment>,
+}
+
+impl GenericPath {
+    /// Gets the last segment, `None` when the path is empty.
+    #[must_use]
+    pub fn last_segment(&self) -> Option<&PathSegment> {
+        self.segments.values().last()
+    }
+}
+
+impl Parser for GenericPath {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        Ok(Self {
+            leading: Option::<PathSep>::parser(tokens).refine_err::<Self>()?,
+            segments: parse_path(tokens).refine_err::<Self>()?,
+        })
+    }
+}
+
+impl ToTokens for GenericPath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.leading.to_tokens(tokens);
+        self.segments.to_tokens(tokens);
+    }
+}
+
+/// A type expression. References, raw pointers, tuples, arrays, slices, the never type,
+/// `dyn` and `impl` trait types, function pointers and paths are supported. A parenthesized
+/// type `(T)` is parsed as a tuple with one element. Qualified paths (`<T as Trait>::X`),
+/// `unsafe`/`extern` function pointers and named function pointer arguments are not
+/// supported.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "&'a mut [T] (u8, *const [i32; 4]) Option<&str>".to_token_iter();
+///
+/// let TypeExpr::Reference { lifetime, mutability, ty, .. } =
+///     TypeExpr::parse(&mut token_iter).unwrap() else { panic!() };
+/// assert_eq!(lifetime.unwrap().name(), "a");
+/// assert!(mutability.is_some());
+/// assert!(matches!(*ty, TypeExpr::Slice(_)));
+///
+/// let tuple = TypeExpr::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(tuple, "(u8, *const [i32; 4])");
+///
+/// let option = TypeExpr::parse(&mut token_iter).unwrap();
+/// assert_eq!(option.last_segment().unwrap().ident, "Option");
+///
+/// let mut token_iter = "Box<dyn Fn(u8) -> u8 + Send> fn(&str) -> bool".to_token_iter();
+/// let boxed = TypeExpr::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(boxed, "Box<dyn Fn(u8) -> u8 + Send>");
+/// let TypeExpr::FnPointer { args, .. } = TypeExpr::parse(&mut token_iter).unwrap() else {
+///     panic!()
+/// };
+/// assert_eq!(args.first.content.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub enum TypeExpr {
+    /// `&'a mut T`
+    Reference {
+        /// The `&`
+        and: And,
+        /// The optional lifetime
+        lifetime: Option<Lifetime>,
+        /// The optional `mut`
+        mutability: Option<Mut>,
+        /// The referenced type
+        ty: Box<TypeExpr>,
+    },
+    /// `*const T` or `*mut T`
+    Pointer {
+        /// The `*`
+        star: Star,
+        /// `const` or `mut`
+        mutability: Either<Const, Mut>,
+        /// The pointed to type
+        ty: Box<TypeExpr>,
+    },
+    /// `(A, B)` or the unit type `()`
+    Tuple(ParenthesisGroupContaining<CommaDelimitedVec<TypeExpr>>),
+    /// `[T; N]`, the length is kept as tokens
+    Array(BracketGroupContaining<Cons<Box<TypeExpr>, Semicolon, TokenStream>>),
+    /// `[T]`
+    Slice(BracketGroupContaining<Box<TypeExpr>>),
+    /// `!`
+    Never(Bang),
+    /// `dyn Trait + Send`
+    TraitObject {
+        /// The `dyn`
+        dyn_kw: Dyn,
+        /// The bounds
+        bounds: Bounds<TypeBound>,
+    },
+    /// `impl Trait + Send`
+    ImplTrait {
+        /// The `impl`
+        impl_kw: Impl,
+        /// The bounds
+        bounds: Bounds<TypeBound>,
+    },
+    /// `fn(u8) -> u8` or `for<'a> fn(&'a str)`
+    FnPointer {
+        /// The higher-ranked lifetimes
+        for_lifetimes: Option<ForLifetimes>,
+        /// The `fn`
+        fn_kw: FnKw,
+        /// The argument types and the optional return type
+        args: Box<FnArgs>,
+    },
+    /// `a::b::C<T>`
+    Path(GenericPath),
+}
+
+impl TypeExpr {
+    /// Gets the path of a [`TypeExpr::Path`].
+    #[must_use]
+    pub const fn as_path(&self) -> Option<&GenericPath> {
+        match self {
+            Self::Path(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Gets the last segment of a [`TypeExpr::Path`].
+    #[must_use]
+    pub fn last_segment(&self) -> Option<&PathSegment> {
+        self.as_path().and_then(GenericPath::last_segment)
+    }
+}
+
+impl Parser for TypeExpr {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let mut err = Error::no_error();
+        if let Ok(reference) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Reference {
+                and: And::parser(tokens)?,
+                lifetime: Option::<Lifetime>::parser(tokens)?,
+                mutability: Option::<Mut>::parser(tokens)?,
+                ty: Box::<TypeExpr>::parser(tokens)?,
+            })
+        })) {
+            return Ok(reference);
+        }
+        if let Ok(pointer) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::Pointer {
+                star: Star::parser(tokens)?,
+                mutability: Either::<Const, Mut>::parser(tokens)?,
+                ty: Box::<TypeExpr>::parser(tokens)?,
+            })
+        })) {
+            return Ok(pointer);
+        }
+        if let Ok(tuple) = err.upgrade(ParenthesisGroupContaining::parse(tokens)) {
+            return Ok(Self::Tuple(tuple));
+        }
+        if let Ok(array) = err.upgrade(BracketGroupContaining::parse(tokens)) {
+            return Ok(Self::Array(array));
+        }
+        if let Ok(slice) = err.upgrade(BracketGroupContaining::parse(tokens)) {
+            return Ok(Self::Slice(slice));
+        }
+        if let Ok(never) = err.upgrade(Bang::parse(tokens)) {
+            return Ok(Self::Never(never));
+        }
+        // before paths, these start with keywords that would parse as path segments
+        if let Ok(object) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::TraitObject {
+                dyn_kw: Dyn::parser(tokens)?,
+                bounds: Bounds::parser(tokens)?,
+            })
+        })) {
+            return Ok(object);
+        }
+        if let Ok(impl_trait) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::ImplTrait {
+                impl_kw: Impl::parser(tokens)?,
+                bounds: Bounds::parser(tokens)?,
+            })
+        })) {
+            return Ok(impl_trait);
+        }
+        if let Ok(fn_pointer) = err.upgrade(tokens.transaction(|tokens| {
+            Ok(Self::FnPointer {
+                for_lifetimes: Option::<ForLifetimes>::parser(tokens)?,
+                fn_kw: FnKw::parser(tokens)?,
+                args: Box::<FnArgs>::parser(tokens)?,
+            })
+        })) {
+            return Ok(fn_pointer);
+        }
+        if let Ok(path) = err.upgrade(GenericPath::parse(tokens)) {
+            return Ok(Self::Path(path));
+        }
+        Err(err)
+    }
+}
+
+impl ToTokens for TypeExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Reference {
+                and,
+                lifetime,
+                mutability,
+                ty,
+            } => {
+                and.to_tokens(tokens);
+                lifetime.to_tokens(tokens);
+                mutability.to_tokens(tokens);
+                ty.to_tokens(tokens);
+            }
+            Self::Pointer {
+                star,
+                mutability,
+                ty,
+            } => {
+                star.to_tokens(tokens);
+                mutability.to_tokens(tokens);
+                ty.to_tokens(tokens);
+            }
+            Self::Tuple(tuple) => tuple.to_tokens(tokens),
+            Self::Array(array) => array.to_tokens(tokens),
+            Self::Slice(slice) => slice.to_tokens(tokens),
+            Self::Never(never) => never.to_tokens(tokens),
+            Self::TraitObject { dyn_kw, bounds } => {
+                dyn_kw.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+            Self::ImplTrait { impl_kw, bounds } => {
+                impl_kw.to_tokens(tokens);
+                bounds.to_tokens(tokens);
+            }
+            Self::FnPointer {
+                for_lifetimes,
+                fn_kw,
+                args,
+            } => {
+                for_lifetimes.to_tokens(tokens);
+                fn_kw.to_tokens(tokens);
+                args.to_tokens(tokens);
+            }
+            Self::Path(path) => path.to_tokens(tokens),
+        }
+    }
+}
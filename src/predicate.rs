@@ -0,0 +1,209 @@
+//! This module contains [`Validated<T, P>`] which rejects parsed values that do not fulfill a
+//! [`Predicate`] and a set of stock predicates.
+
+#[allow(clippy::wildcard_imports)]
+use crate::*;
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// A check on a parsed value. This is implemented on zero sized marker types which are then
+/// used as `P` in [`Validated<T, P>`].
+pub trait Predicate<T> {
+    /// Returns `Ok(())` when `value` is accepted or the reason why it got rejected.
+    ///
+    /// # Errors
+    ///
+    /// The `String` describing why `value` is rejected.
+    fn check(value: &T) -> std::result::Result<(), String>;
+}
+
+/// Parses `T` and succeeds only when the predicate `P` accepts it. Otherwise a
+/// `ErrorKind::Other` with the reason from the predicate is returned, pointing at the first
+/// token of `T`.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "snake_case CamelCase 42 1000".to_token_iter();
+///
+/// let ident = <Validated<Ident, IsSnakeCase>>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(ident, "snake_case");
+/// assert!(<Validated<Ident, IsSnakeCase>>::parse(&mut token_iter).is_err());
+/// let _ = <Validated<Ident, IsCamelCase>>::parse(&mut token_iter).unwrap();
+///
+/// let number = <Validated<LiteralInteger, InRange<0, 100>>>::parse(&mut token_iter).unwrap();
+/// assert_eq!(number.value(), 42);
+/// assert!(<Validated<LiteralInteger, InRange<0, 100>>>::parse(&mut token_iter).is_err());
+/// ```
+pub struct Validated<T, P>(pub T, PhantomData<P>);
+
+impl<T, P> Validated<T, P> {
+    /// Destructures `Validated<T, P>` to get the inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Parse, P: Predicate<T>> Parser for Validated<T, P> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let value = T::parser(tokens)?;
+        match P::check(&value) {
+            Ok(()) => Ok(Self(value, PhantomData)),
+            Err(reason) => Error::other(at, tokens, reason),
+        }
+    }
+}
+
+impl<T: ToTokens, P> ToTokens for Validated<T, P> {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<T, P> Deref for Validated<T, P> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Clone, P> Clone for Validated<T, P> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: std::fmt::Debug, P> std::fmt::Debug for Validated<T, P> {
+    #[mutants::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!(
+            "Validated<{}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<P>()
+        ))
+        .field(&self.0)
+        .finish()
+    }
+}
+
+/// Accepts a [`LiteralInteger`] within `MIN..=MAX`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InRange<const MIN: u128, const MAX: u128>;
+
+impl<const MIN: u128, const MAX: u128> Predicate<LiteralInteger> for InRange<MIN, MAX> {
+    fn check(value: &LiteralInteger) -> std::result::Result<(), String> {
+        if (MIN..=MAX).contains(&value.value()) {
+            Ok(())
+        } else {
+            Err(format!("{} is not in range {MIN}..={MAX}", value.value()))
+        }
+    }
+}
+
+/// Accepts non empty [`LiteralString`], `String`, `Vec<T>`, [`DelimitedVec<T, D>`] and
+/// [`TokenStream`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonEmpty;
+
+impl Predicate<LiteralString> for NonEmpty {
+    fn check(value: &LiteralString) -> std::result::Result<(), String> {
+        non_empty(value.unescaped().is_empty(), "string")
+    }
+}
+
+impl Predicate<String> for NonEmpty {
+    fn check(value: &String) -> std::result::Result<(), String> {
+        non_empty(value.is_empty(), "string")
+    }
+}
+
+impl<T> Predicate<Vec<T>> for NonEmpty {
+    fn check(value: &Vec<T>) -> std::result::Result<(), String> {
+        non_empty(value.is_empty(), "list")
+    }
+}
+
+impl<T, D> Predicate<DelimitedVec<T, D>> for NonEmpty {
+    fn check(value: &DelimitedVec<T, D>) -> std::result::Result<(), String> {
+        non_empty(value.is_empty(), "list")
+    }
+}
+
+impl Predicate<TokenStream> for NonEmpty {
+    fn check(value: &TokenStream) -> std::result::Result<(), String> {
+        non_empty(value.is_empty(), "token stream")
+    }
+}
+
+fn non_empty(is_empty: bool, what: &str) -> std::result::Result<(), String> {
+    if is_empty {
+        Err(format!("expected a non empty {what}"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Accepts [`Ident`] and [`CachedIdent`] in `snake_case`. Leading and trailing underscores
+/// are permitted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IsSnakeCase;
+
+/// Accepts [`Ident`] and [`CachedIdent`] in `CamelCase` (upper camel case).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IsCamelCase;
+
+fn is_snake_case(ident: &str) -> bool {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident).trim_matches('_');
+    !ident.contains("__") && !ident.chars().any(char::is_uppercase)
+}
+
+fn is_camel_case(ident: &str) -> bool {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    ident.chars().next().is_some_and(char::is_uppercase) && !ident.contains('_')
+}
+
+macro_rules! impl_case_predicate {
+    ($($predicate:ident => $check:ident, $case:literal;)*) => {
+        $(
+            impl Predicate<Ident> for $predicate {
+                fn check(value: &Ident) -> std::result::Result<(), String> {
+                    Self::check(&value.to_string())
+                }
+            }
+
+            impl Predicate<CachedIdent> for $predicate {
+                fn check(value: &CachedIdent) -> std::result::Result<(), String> {
+                    Self::check(&value.as_str())
+                }
+            }
+
+            impl Predicate<&str> for $predicate {
+                fn check(value: &&str) -> std::result::Result<(), String> {
+                    if $check(value) {
+                        Ok(())
+                    } else {
+                        Err(format!("`{value}` is not {}", $case))
+                    }
+                }
+            }
+
+            impl Predicate<String> for $predicate {
+                fn check(value: &String) -> std::result::Result<(), String> {
+                    Self::check(&value.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_case_predicate! {
+    IsSnakeCase => is_snake_case, "snake_case";
+    IsCamelCase => is_camel_case, "CamelCase";
+}
@@ -1,6 +1,7 @@
 //! This module contains types for punctuation tokens. These are used to represent single and
 //! multi character punctuation tokens. For single character punctuation tokens, there are
-//! there are [`PunctAny`], [`PunctAlone`] and [`PunctJoint`] types.
+//! there are [`PunctAny`], [`PunctAlone`] and [`PunctJoint`] types. [`PunctSpaced`] matches any
+//! spacing like [`PunctAny`] but keeps the original spacing when emitted.
 #![allow(clippy::module_name_repetitions)]
 
 pub use proc_macro2::Spacing;
@@ -181,6 +182,81 @@ impl<const C: char> From<PunctAlone<C>> for TokenTree {
     }
 }
 
+/// A single character punctuation token with any kind of [`Spacing`]. Unlike [`PunctAny`] the
+/// spacing is kept and emitted back, thus `a--b` and `a - -b` round-trip unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "a--b - -c".to_token_iter();
+///
+/// let parsed = Vec::<Either<Ident, PunctSpaced<'-'>>>::parse(&mut token_iter).unwrap();
+/// assert_tokens_eq!(parsed, "a--b - -c");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PunctSpaced<const C: char> {
+    spacing: Spacing,
+}
+
+impl<const C: char> PunctSpaced<C> {
+    /// Create a new [`PunctSpaced`] object with the given spacing.
+    #[must_use]
+    pub const fn new(spacing: Spacing) -> Self {
+        Self { spacing }
+    }
+
+    /// Get the `char` value this object represents.
+    #[must_use]
+    pub const fn as_char(&self) -> char {
+        C
+    }
+
+    /// Get the original spacing.
+    #[must_use]
+    pub const fn spacing(&self) -> Spacing {
+        self.spacing
+    }
+}
+
+/// Defaults to [`Spacing::Alone`].
+impl<const C: char> Default for PunctSpaced<C> {
+    fn default() -> Self {
+        Self::new(Spacing::Alone)
+    }
+}
+
+impl<const C: char> Parser for PunctSpaced<C> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == C => Ok(Self {
+                spacing: punct.spacing(),
+            }),
+            at => Error::unexpected_token(at, tokens),
+        }
+    }
+}
+
+impl<const C: char> ToTokens for PunctSpaced<C> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        Punct::new(C, self.spacing).to_tokens(tokens);
+    }
+}
+
+#[mutants::skip]
+impl<const C: char> std::fmt::Debug for PunctSpaced<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PunctSpaced<{C:?}, {:?}>", self.spacing)
+    }
+}
+
+/// Convert a [`PunctSpaced`] object into a [`TokenTree`].
+impl<const C: char> From<PunctSpaced<C>> for TokenTree {
+    fn from(punct: PunctSpaced<C>) -> Self {
+        TokenTree::Punct(Punct::new(C, punct.spacing))
+    }
+}
+
 #[test]
 fn test_alone_punct_into_tt() {
     let mut token_iter = "+ +".to_token_iter();
@@ -1,19 +1,39 @@
 //! Parsers for rusts types.
 
 use crate::{
-    Error, Ident, LiteralCharacter, LiteralInteger, Parse, Parser, RefineErr, Result, Span,
-    ToTokens, TokenIter, TokenStream, TokenTree,
+    Error, Ident, LiteralCharacter, LiteralFloat, LiteralInteger, LiteralSignedInteger,
+    LiteralString, Parse, Parser, RefineErr, Result, Span, ToTokens, TokenIter, TokenStream,
+    TokenTree,
 };
 
+/// Fails when `lit` has a suffix other than `expected`.
+fn check_suffix(
+    lit: &LiteralInteger,
+    expected: &str,
+    at: Option<TokenTree>,
+    tokens: &TokenIter,
+) -> Result<()> {
+    if lit.suffix().is_empty() || lit.suffix() == expected {
+        Ok(())
+    } else {
+        Error::other(
+            at,
+            tokens,
+            format!("expected suffix `{expected}`, got `{}`", lit.suffix()),
+        )
+    }
+}
+
 // Parser and ToTokens for unsigned integer types
 macro_rules! impl_unsigned_integer {
     ($($ty:ty),*) => {
         $(
-            #[doc = stringify!(Parse $ty may have a positive sign but no suffix)]
+            #[doc = stringify!(Parse $ty may have a positive sign and a $ty suffix)]
             impl Parser for $ty {
                 fn parser(tokens: &mut TokenIter) -> Result<Self> {
                     let at = tokens.clone().next();
                     let lit = crate::Cons::<Option<crate::Plus>, LiteralInteger>::parser(tokens).refine_err::<Self>()?;
+                    check_suffix(&lit.second, stringify!($ty), at.clone(), tokens).refine_err::<Self>()?;
                     <$ty>::try_from(lit.second.value()).map_err(|e| Error::dynamic::<Self>(at, tokens, e))
                 }
             }
@@ -43,46 +63,105 @@ impl_unsigned_integer! {u8, u16, u32, u64, u128, usize}
 macro_rules! impl_signed_integer {
     ($($ty:ty),*) => {
         $(
-            #[doc = stringify!(Parse $ty may have a positive or negative sign but no suffix)]
+            #[doc = stringify!(Parse $ty may have a positive or negative sign and a $ty suffix)]
             impl Parser for $ty {
                 fn parser(tokens: &mut TokenIter) -> Result<Self> {
                     let at = tokens.clone().next();
-                    let lit = crate::Cons::<Option<crate::Either<crate::Plus, crate::Minus>>, LiteralInteger>::parser(tokens).refine_err::<Self>()?;
-                    <$ty>::try_from(lit.second.value())
-                    .map_err(|e| Error::dynamic::<Self>(at, tokens, e))
-                    .and_then(|value| {
-                        match lit.first {
-                            Some(crate::Either::Second(_)) => Ok(-value),
-                            _ => Ok(value),
-                        }
-                    })
+                    let lit = LiteralSignedInteger::parser(tokens).refine_err::<Self>()?;
+                    check_suffix(lit.literal(), stringify!($ty), at.clone(), tokens).refine_err::<Self>()?;
+                    <$ty>::try_from(lit.value()).map_err(|e| Error::dynamic::<Self>(at, tokens, e))
                 }
             }
 
             #[doc = stringify!(Emit a literal $ty with negative sign and without suffix)]
             impl ToTokens for $ty {
                 fn to_tokens(&self, tokens: &mut TokenStream) {
-                    if *self < 0 {
-                        crate::Minus::new().to_tokens(tokens);
-                    }
-                    LiteralInteger::new(self.abs().try_into().unwrap()).to_tokens(tokens);
+                    #[allow(clippy::cast_lossless)]
+                    LiteralSignedInteger::new(*self as i128).to_tokens(tokens);
                 }
             }
 
             #[doc = stringify!(Emit a literal $ty with negative sign and without suffix)]
             impl ToTokens for &$ty {
                 fn to_tokens(&self, tokens: &mut TokenStream) {
-                    if **self < 0 {
+                    #[allow(clippy::cast_lossless)]
+                    LiteralSignedInteger::new(**self as i128).to_tokens(tokens);
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_integer! {i8, i16, i32, i64, i128, isize}
+
+// Parser and ToTokens for floating point types
+/// Emits the path `ty::name` for float constants that have no literal representation.
+fn float_constant(ty: &str, name: &str, tokens: &mut TokenStream) {
+    Ident::new(ty, Span::call_site()).to_tokens(tokens);
+    crate::PathSep::new().to_tokens(tokens);
+    Ident::new(name, Span::call_site()).to_tokens(tokens);
+}
+
+macro_rules! impl_float {
+    ($($ty:ident => $unsuffixed:ident),*) => {
+        $(
+            #[doc = stringify!(Parse $ty from a float literal which may have a positive or negative sign and a $ty suffix. Values out of range for $ty are rejected)]
+            impl Parser for $ty {
+                fn parser(tokens: &mut TokenIter) -> Result<Self> {
+                    let at = tokens.clone().next();
+                    let lit = crate::Cons::<Option<crate::Either<crate::Plus, crate::Minus>>, LiteralFloat>::parser(tokens).refine_err::<Self>()?;
+                    let suffix = lit.second.suffix();
+                    if !suffix.is_empty() && suffix != stringify!($ty) {
+                        return Error::other(
+                            at,
+                            tokens,
+                            format!("expected suffix `{}`, got `{suffix}`", stringify!($ty)),
+                        );
+                    }
+                    let value = lit.second.parse_value::<$ty>().unwrap_or(<$ty>::INFINITY);
+                    if !value.is_finite() {
+                        return Error::other(
+                            at,
+                            tokens,
+                            format!("float literal out of range for `{}`", stringify!($ty)),
+                        );
+                    }
+                    match lit.first {
+                        Some(crate::Either::Second(_)) => Ok(-value),
+                        _ => Ok(value),
+                    }
+                }
+            }
+
+            #[doc = stringify!(Emit a literal $ty with negative sign and without suffix. Infinite and NaN values are emitted as $ty::INFINITY and $ty::NAN paths)]
+            impl ToTokens for $ty {
+                fn to_tokens(&self, tokens: &mut TokenStream) {
+                    if self.is_nan() {
+                        float_constant(stringify!($ty), "NAN", tokens);
+                        return;
+                    }
+                    if self.is_sign_negative() {
                         crate::Minus::new().to_tokens(tokens);
                     }
-                    LiteralInteger::new(self.abs().try_into().unwrap()).to_tokens(tokens);
+                    if self.is_infinite() {
+                        float_constant(stringify!($ty), "INFINITY", tokens);
+                    } else {
+                        crate::Literal::$unsuffixed(self.abs()).to_tokens(tokens);
+                    }
+                }
+            }
+
+            #[doc = stringify!(Emit a literal $ty with negative sign and without suffix. Infinite and NaN values are emitted as $ty::INFINITY and $ty::NAN paths)]
+            impl ToTokens for &$ty {
+                fn to_tokens(&self, tokens: &mut TokenStream) {
+                    (**self).to_tokens(tokens);
                 }
             }
         )*
     };
 }
 
-impl_signed_integer! {i8, i16, i32, i64, i128, isize}
+impl_float! {f32 => f32_unsuffixed, f64 => f64_unsuffixed}
 
 // Parser and ToTokens for char
 impl Parser for char {
@@ -127,13 +206,58 @@ impl ToTokens for bool {
 /// kind of `TokenTree` and converts it `.to_string()`. Thus it looses its relationship to the
 /// type of the underlying token/syntactic entity. This is only useful when one wants to parse
 /// string like parameters in a macro that are not emitted later. This limits the use of this
-/// parser significantly.
+/// parser significantly. Use [`UnescapedString`] to parse the unescaped content of string
+/// literals.
 impl Parser for String {
     fn parser(tokens: &mut TokenIter) -> Result<Self> {
         TokenTree::parse_with(tokens, |token, _| Ok(token.to_string())).refine_err::<Self>()
     }
 }
 
+/// Parses a (raw) string literal into its unescaped content. This is the string literal mode
+/// of the `String` parser. When emitted it becomes a escaped string literal again.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#" "a\nb" foo "#.to_token_iter();
+///
+/// let string = UnescapedString::parse(&mut token_iter).unwrap();
+/// assert_eq!(*string, "a\nb");
+/// assert_tokens_eq!(string, r#" "a\nb" "#);
+/// assert!(UnescapedString::parse(&mut token_iter).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnescapedString(pub String);
+
+impl Parser for UnescapedString {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let lit = LiteralString::parser(tokens).refine_err::<Self>()?;
+        Ok(Self(lit.unescaped().to_string()))
+    }
+}
+
+impl ToTokens for UnescapedString {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        LiteralString::from_str(&self.0).to_tokens(tokens);
+    }
+}
+
+impl std::ops::Deref for UnescapedString {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<UnescapedString> for String {
+    fn from(string: UnescapedString) -> Self {
+        string.0
+    }
+}
+
 /// Tokenizes a `&str`. Panics if the input string does not tokenize.
 ///
 /// # Example
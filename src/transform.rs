@@ -367,7 +367,7 @@ impl<T: Default + ToTokens> Default for IntoIdent<T> {
 /// assert_tokens_eq!(parsed, "foo 123");
 /// # assert_tokens_eq!(parsed.into_inner(), "foo 123")
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntoTokenStream<T>(TokenStream, PhantomData<T>);
 
 impl<T: ToTokens> IntoTokenStream<T> {
@@ -433,3 +433,234 @@ impl<T: Default + ToTokens> Default for IntoTokenStream<T> {
 /// # <TokenStreamUntil<Plus>>::parser(&mut token_iter).unwrap_err();
 /// ```
 pub type TokenStreamUntil<T> = IntoTokenStream<Cons<Vec<Cons<Except<T>, TokenTree>>, Expect<T>>>;
+
+/// Transforms a parsed `T` into some other type. This is implemented on zero sized marker
+/// types which are then used as `F` in [`Map<T, F>`].
+pub trait Transform<T> {
+    /// The result of the transformation.
+    type Output;
+
+    /// Transforms `value` into `Self::Output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason when `value` can not be transformed, [`Map`] turns this into a
+    /// parse error at the position of `value`.
+    fn transform(value: T) -> std::result::Result<Self::Output, String>;
+}
+
+/// Parses `T` and transforms it with `F`. This is the declarative counterpart of
+/// [`Parse::parse_with()`], it needs no closure and thus can be used as field type in
+/// `unsynn!` structs. `ToTokens` emits the transformed value.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = r#" "hello" foo, bar "#.to_token_iter();
+///
+/// let upper = <Map<LiteralString, ToUppercase>>::parser(&mut token_iter).unwrap();
+/// assert_eq!(upper.as_str(), "HELLO");
+///
+/// let vec = <Map<CommaDelimitedVec<Ident>, IntoVec>>::parser(&mut token_iter).unwrap();
+/// assert_eq!(vec.len(), 2);
+/// assert_tokens_eq!(vec, "foo bar");
+/// ```
+pub struct Map<T, F: Transform<T>>(pub F::Output, PhantomData<(T, F)>);
+
+impl<T, F: Transform<T>> Map<T, F> {
+    /// Creates a `Map` by transforming `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason when `value` can not be transformed.
+    pub fn new(value: T) -> std::result::Result<Self, String> {
+        Ok(Self(F::transform(value)?, PhantomData))
+    }
+
+    /// Destructures `Map<T, F>` to get the transformed value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> F::Output {
+        self.0
+    }
+}
+
+impl<T: Parse, F: Transform<T>> Parser for Map<T, F> {
+    fn parser(tokens: &mut TokenIter) -> Result<Self> {
+        let at = tokens.clone().next();
+        let value = T::parser(tokens)?;
+        Self::new(value).or_else(|reason| Error::other(at, tokens, reason))
+    }
+}
+
+impl<T, F: Transform<T>> ToTokens for Map<T, F>
+where
+    F::Output: ToTokens,
+{
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl<T, F: Transform<T>> Deref for Map<T, F> {
+    type Target = F::Output;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, F: Transform<T>> Clone for Map<T, F>
+where
+    F::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T, F: Transform<T>> std::fmt::Debug for Map<T, F>
+where
+    F::Output: std::fmt::Debug,
+{
+    #[mutants::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!(
+            "Map<{}, {}>",
+            std::any::type_name::<T>(),
+            std::any::type_name::<F>()
+        ))
+        .field(&self.0)
+        .finish()
+    }
+}
+
+/// [`Transform`] that converts [`LiteralString`], [`Ident`] and [`CachedIdent`] to upper case.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ToUppercase;
+
+/// [`Transform`] that converts [`LiteralString`], [`Ident`] and [`CachedIdent`] to lower case.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ToLowercase;
+
+macro_rules! impl_case_transform {
+    ($($transform:ident => $method:ident),*) => {
+        $(
+            impl Transform<LiteralString> for $transform {
+                type Output = LiteralString;
+
+                fn transform(value: LiteralString) -> std::result::Result<LiteralString, String> {
+                    Ok(LiteralString::from_str(value.unescaped().$method()))
+                }
+            }
+
+            impl Transform<Ident> for $transform {
+                type Output = Ident;
+
+                fn transform(value: Ident) -> std::result::Result<Ident, String> {
+                    convert_ident_case(&value, str::$method)
+                }
+            }
+
+            impl Transform<CachedIdent> for $transform {
+                type Output = CachedIdent;
+
+                fn transform(mut value: CachedIdent) -> std::result::Result<CachedIdent, String> {
+                    value.set(<$transform as Transform<Ident>>::transform(value.clone().into_inner())?);
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+/// Converts the case of `value` with `convert`, raw identifiers stay raw. Fails when the
+/// result is not a valid identifier.
+fn convert_ident_case(
+    value: &Ident,
+    convert: impl Fn(&str) -> String,
+) -> std::result::Result<Ident, String> {
+    let source = value.to_string();
+    let (raw, converted) = match source.strip_prefix("r#") {
+        Some(name) => (true, convert(name)),
+        None => (false, convert(&source)),
+    };
+    let check = if raw {
+        format!("r#{converted}")
+    } else {
+        converted.clone()
+    };
+    // `Ident::new` panics on invalid identifiers, let the lexer validate first
+    let valid = std::str::FromStr::from_str(&check)
+        .is_ok_and(|lexed: TokenStream| Ident::parse_all(&mut lexed.into_token_iter()).is_ok());
+    if !valid {
+        return Err(format!("{check:?} is not a valid identifier"));
+    }
+    Ok(if raw {
+        Ident::new_raw(&converted, value.span())
+    } else {
+        Ident::new(&converted, value.span())
+    })
+}
+
+impl_case_transform! {ToUppercase => to_uppercase, ToLowercase => to_lowercase}
+
+/// [`Transform`] that converts [`DelimitedVec`], [`Repeats`] and [`CountedRepeats`] into a
+/// `Vec<T>`, dropping the delimiters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntoVec;
+
+impl<T, D> Transform<DelimitedVec<T, D>> for IntoVec {
+    type Output = Vec<T>;
+
+    fn transform(value: DelimitedVec<T, D>) -> std::result::Result<Vec<T>, String> {
+        Ok(value.into())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, T, D> Transform<Repeats<MIN, MAX, T, D>> for IntoVec {
+    type Output = Vec<T>;
+
+    fn transform(value: Repeats<MIN, MAX, T, D>) -> std::result::Result<Vec<T>, String> {
+        Ok(value.into())
+    }
+}
+
+impl<N, T, D> Transform<CountedRepeats<N, T, D>> for IntoVec {
+    type Output = Vec<T>;
+
+    fn transform(value: CountedRepeats<N, T, D>) -> std::result::Result<Vec<T>, String> {
+        Ok(value.into())
+    }
+}
+
+/// [`Transform`] that converts `T` into `U` by its `Into<U>` implementation.
+///
+/// # Example
+///
+/// ```
+/// # use unsynn::*;
+/// let mut token_iter = "foo".to_token_iter();
+///
+/// let tt = <Map<Ident, Convert<TokenTree>>>::parser(&mut token_iter).unwrap();
+/// assert!(matches!(*tt, TokenTree::Ident(_)));
+/// ```
+pub struct Convert<U>(PhantomData<U>);
+
+impl<T: Into<U>, U> Transform<T> for Convert<U> {
+    type Output = U;
+
+    fn transform(value: T) -> std::result::Result<U, String> {
+        Ok(value.into())
+    }
+}
+
+impl<U> std::fmt::Debug for Convert<U> {
+    #[mutants::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Convert<{}>", std::any::type_name::<U>())
+    }
+}
@@ -0,0 +1,71 @@
+//! testing attributes and doc comments
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+unsynn! {
+    struct Documented {
+        docs: Vec<DocComment>,
+        attributes: Vec<OuterAttribute<Meta>>,
+        name: Ident,
+    }
+}
+
+#[test]
+fn test_documented_item() {
+    let mut token_iter = r#"
+        /// Some item
+        ///   indented
+        #[rename(to = "other")]
+        #[skip]
+        item
+    "#
+    .to_token_iter();
+
+    let documented = Documented::parse_all(&mut token_iter).unwrap();
+    let text: Vec<_> = documented.docs.iter().map(DocComment::text).collect();
+    assert_eq!(text, [" Some item", "   indented"]);
+    assert_eq!(
+        documented.attributes[0].content.content.name().as_str(),
+        "rename"
+    );
+    assert!(matches!(
+        documented.attributes[1].content.content,
+        Meta::Path(_)
+    ));
+
+    // doc comments survive a round trip
+    let mut token_iter = documented.to_token_iter();
+    let again = Documented::parse_all(&mut token_iter).unwrap();
+    assert_eq!(again.docs[1].text(), "   indented");
+}
+
+#[test]
+fn test_attributes() {
+    let mut token_iter = "#![inner] #[outer] #[doc(hidden)]".to_token_iter();
+
+    assert!(<OuterAttribute>::parse(&mut token_iter).is_err());
+    let inner = <InnerAttribute>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(inner, "#![inner]");
+    assert!(<InnerAttribute>::parse(&mut token_iter).is_err());
+    let _ = <OuterAttribute>::parse(&mut token_iter).unwrap();
+    // `doc(...)` is not a doc comment
+    assert!(DocComment::parse(&mut token_iter).is_err());
+
+    assert_tokens_eq!(
+        InnerAttribute::new(quote! {deny(warnings)}),
+        "#![deny(warnings)]"
+    );
+    assert_tokens_eq!(DocComment::new_inner("inner"), r#"#![doc = "inner"]"#);
+    assert!(DocComment::new_inner("inner").is_inner());
+
+    // the parsed `#` is emitted back with its spacing
+    let mut token_iter = r#"# ![inner] # ![doc = "spaced"]"#.to_token_iter();
+    assert_tokens_eq!(
+        <InnerAttribute>::parse(&mut token_iter).unwrap(),
+        "# ![inner]"
+    );
+    assert_tokens_eq!(
+        DocComment::parse(&mut token_iter).unwrap(),
+        r#"# ![doc = "spaced"]"#
+    );
+}
@@ -60,3 +60,64 @@ fn test_either_to_tokens() {
     either.to_tokens(&mut tokens);
     assert_eq!(tokens.to_string(), "test");
 }
+
+#[test]
+fn test_unordered() {
+    type Clauses = Unordered<(
+        Cons<Ident, Assign, LiteralInteger>,
+        Option<Colon>,
+        LiteralString,
+    )>;
+
+    let mut token_iter = r#""str" a = 1 :"#.to_token_iter();
+    let clauses = Clauses::parse_all(&mut token_iter).unwrap();
+    assert!(clauses.1.is_some());
+    assert_tokens_eq!(clauses, r#"a = 1 : "str""#);
+
+    // optional member left out
+    let mut token_iter = r#""str" a = 1"#.to_token_iter();
+    let clauses = Clauses::parse_all(&mut token_iter).unwrap();
+    assert!(clauses.1.is_none());
+    assert_tokens_eq!(clauses, r#"a = 1 "str""#);
+
+    // duplicate
+    let mut token_iter = r#""str" a = 1 "again""#.to_token_iter();
+    let err = Clauses::parse(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("duplicate"));
+
+    // missing
+    let mut token_iter = "a = 1".to_token_iter();
+    let err = Clauses::parse(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("missing required"));
+}
+
+#[test]
+fn test_unordered_delimited() {
+    type Clauses = Unordered<(Cons<Ident, Assign, LiteralInteger>, LiteralString), Comma>;
+
+    let mut token_iter = r#""str", a = 1,"#.to_token_iter();
+    let clauses = Clauses::parse_all(&mut token_iter).unwrap();
+    assert_tokens_eq!(clauses, r#"a = 1, "str""#);
+
+    // missing delimiter stops parsing
+    let mut token_iter = r#""str" a = 1"#.to_token_iter();
+    assert!(Clauses::parse(&mut token_iter).is_err());
+}
+
+#[test]
+fn test_map_case_raw_ident() {
+    let mut token_iter = "r#fn r#Type foo".to_token_iter();
+    let upper = Map::<Ident, ToUppercase>::parse(&mut token_iter).unwrap();
+    assert_eq!(upper.to_string(), "r#FN");
+    let lower = Map::<CachedIdent, ToLowercase>::parse(&mut token_iter).unwrap();
+    assert_eq!(lower.as_str(), "r#type");
+    assert_tokens_eq!(
+        Map::<Ident, ToUppercase>::parse(&mut token_iter).unwrap(),
+        "FOO"
+    );
+
+    // `r#self` is not a valid identifier, this is an error instead of a panic
+    let mut token_iter = "r#SELF".to_token_iter();
+    let err = Map::<Ident, ToLowercase>::parse(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("not a valid identifier"));
+}
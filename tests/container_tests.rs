@@ -88,6 +88,13 @@ fn test_repeats() {
     assert_eq!(vec.len(), 2);
     assert_eq!(vec[0].value.to_string(), "foo");
     assert_eq!(vec[1].value.to_string(), "bar");
+
+    // stops at MAX even when more elements would parse
+    let mut token_iter = "a, b, c, d".to_token_iter();
+    let repeats = Repeats::<1, 2, Ident, Comma>::parse(&mut token_iter).unwrap();
+    assert_eq!(repeats.0.len(), 2);
+    assert_tokens_eq!(repeats, "a, b,");
+    assert_tokens_eq!(token_iter, "c, d");
 }
 
 #[test]
@@ -210,3 +217,219 @@ fn test_lazy_vec_to_tokens() {
 
     assert_tokens_eq!(lazy_vec, str "a b c ;");
 }
+
+#[test]
+fn test_counted_repeats() {
+    let mut token_iter = "3: a b c d".to_token_iter();
+    let counted =
+        CountedRepeats::<Cons<LiteralInteger, Colon>, Ident>::parse(&mut token_iter).unwrap();
+    assert_eq!(counted.count.first.value(), 3);
+    assert_eq!(counted.len(), 3);
+    assert_tokens_eq!(counted, "3: a b c");
+    assert_tokens_eq!(token_iter, "d");
+
+    // zero elements
+    let mut token_iter = "0 a".to_token_iter();
+    let counted = CountedRepeats::<u8, Ident>::parse(&mut token_iter).unwrap();
+    assert!(counted.is_empty());
+    assert_tokens_eq!(counted, "0");
+
+    // with delimiters
+    let mut token_iter = "2 a, b, c".to_token_iter();
+    let counted = CountedRepeats::<usize, Ident, Comma>::parse(&mut token_iter).unwrap();
+    let vec: Vec<Ident> = counted.into();
+    assert_eq!(vec.len(), 2);
+
+    // to few elements
+    let mut token_iter = "3 a b".to_token_iter();
+    assert!(CountedRepeats::<usize, Ident>::parse(&mut token_iter).is_err());
+
+    // huge counts are an error, not an allocation failure
+    let mut token_iter = "100000000000000 a".to_token_iter();
+    assert!(CountedRepeats::<LiteralInteger, Ident>::parse(&mut token_iter).is_err());
+    let mut token_iter = "1000000000000000000000000000000 a".to_token_iter();
+    assert!(CountedRepeats::<LiteralInteger, Ident>::parse(&mut token_iter).is_err());
+
+    // elements that consume no tokens
+    let mut token_iter = "3 a".to_token_iter();
+    let counted = CountedRepeats::<usize, Nothing>::parse(&mut token_iter).unwrap();
+    assert_eq!(counted.len(), 3);
+    assert_tokens_eq!(token_iter, "a");
+    let mut token_iter = "2".to_token_iter();
+    let counted = CountedRepeats::<usize, Option<Ident>>::parse(&mut token_iter).unwrap();
+    assert_eq!(counted.len(), 2);
+}
+
+#[test]
+fn test_vecdeque() {
+    let mut token_iter = "a b c".to_token_iter();
+    let deque = std::collections::VecDeque::<Ident>::parse(&mut token_iter).unwrap();
+    assert_eq!(deque.len(), 3);
+    assert_tokens_eq!(deque, "a b c");
+}
+
+#[test]
+fn test_sets() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let mut token_iter = "c a b".to_token_iter();
+    let set = BTreeSet::<Ident>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(set, "a b c");
+
+    let mut token_iter = "c a b".to_token_iter();
+    let set = HashSet::<CachedIdent>::parse(&mut token_iter).unwrap();
+    assert!(set.contains(&CachedIdent::new("b")));
+    assert_tokens_eq!(set, "a b c");
+
+    // the error points at the duplicate
+    let mut token_iter = "a b c b d".to_token_iter();
+    let err = BTreeSet::<Ident>::parse(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("duplicate `b`"));
+    assert_eq!(err.failed_at().unwrap().to_string(), "b");
+    assert_tokens_eq!(token_iter, "a b c b d");
+}
+
+#[test]
+fn test_maps() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut token_iter = "b: 2, a: 1 c: 3".to_token_iter();
+    let map = BTreeMap::<Ident, ColonValue<LiteralInteger>>::parse(&mut token_iter).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_tokens_eq!(map, "a: 1, b: 2");
+    assert_tokens_eq!(token_iter, "c: 3");
+
+    let mut token_iter = r#"name = "foo", value = 42,"#.to_token_iter();
+    let map = HashMap::<CachedIdent, AssignValue<TokenTree>>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(map[&CachedIdent::new("value")].value, "42");
+    assert_tokens_eq!(map, r#"name = "foo", value = 42"#);
+
+    // wrong separator
+    let mut token_iter = "a = 1".to_token_iter();
+    let map = HashMap::<CachedIdent, ColonValue<TokenTree>>::parse(&mut token_iter).unwrap();
+    assert!(map.is_empty());
+
+    let mut token_iter = "a: 1, b: 2, a: 3".to_token_iter();
+    let err =
+        HashMap::<CachedIdent, ColonValue<LiteralInteger>>::parse(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("duplicate key `a`"));
+}
+
+#[test]
+fn test_sync_cell_cow() {
+    use std::borrow::Cow;
+    use std::cell::Cell;
+    use std::sync::{Arc, Mutex, RwLock};
+
+    let mut token_iter = "a b c d e".to_token_iter();
+
+    let arc = Arc::<Ident>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(arc, "a");
+
+    let mutex = Mutex::<Ident>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(mutex, "b");
+    assert_eq!(mutex.lock().unwrap().to_string(), "b");
+
+    let rwlock = Arc::<RwLock<Ident>>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(rwlock, "c");
+
+    let cell = Cell::<Option<Ident>>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(cell, "d");
+    // emitting leaves the value in place
+    assert_tokens_eq!(cell, "d");
+
+    let cow = Cow::<str>::parse(&mut token_iter).unwrap();
+    assert_eq!(cow, "e");
+    let borrowed: Cow<str> = Cow::Borrowed("f");
+    assert_tokens_eq!(borrowed, "f");
+}
+
+#[test]
+fn test_arrays() {
+    let mut token_iter = "[1 0 0] [0, 1, 0] a b".to_token_iter();
+
+    let row = BracketGroupContaining::<[LiteralInteger; 3]>::parse(&mut token_iter).unwrap();
+    assert_eq!(row.content[0].value(), 1);
+
+    let row =
+        BracketGroupContaining::<CommaDelimitedArray<3, LiteralInteger>>::parse(&mut token_iter)
+            .unwrap();
+    assert_eq!(row.content[1].value.value(), 1);
+    assert_tokens_eq!(row, "[0, 1, 0]");
+
+    // too few elements
+    assert!(<[Ident; 3]>::parse(&mut token_iter).is_err());
+    assert_tokens_eq!(token_iter, "a b");
+    let idents = <[Ident; 2]>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(idents, "a b");
+
+    // empty arrays consume nothing
+    let mut token_iter = "a b".to_token_iter();
+    let empty = <[Ident; 0]>::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(empty, "");
+    let empty = DelimitedArray::<0, Ident, Comma>::parse(&mut token_iter).unwrap();
+    assert!(empty.is_empty());
+    assert!(Repeats::<0, 0, Ident>::parse(&mut token_iter)
+        .unwrap()
+        .0
+        .is_empty());
+    assert_tokens_eq!(token_iter, "a b");
+}
+
+#[test]
+fn test_non_empty_vec() {
+    let mut token_iter = "a b c".to_token_iter();
+    let mut idents = NonEmptyVec::<Ident>::parse(&mut token_iter).unwrap();
+    *idents.last_mut() = Ident::new("z", Span::call_site());
+    assert_eq!(idents.first().to_string(), "a");
+    assert_eq!(
+        idents.values().map(ToString::to_string).collect::<Vec<_>>(),
+        ["a", "b", "z"]
+    );
+    assert_eq!((&idents).into_iter().count(), 3);
+    assert_tokens_eq!(idents, "a b z");
+
+    // single element without delimiter
+    let mut token_iter = "a b".to_token_iter();
+    let idents = NonEmptyVec::<Ident, Semicolon>::parse(&mut token_iter).unwrap();
+    assert!(std::ptr::eq(idents.first(), idents.last()));
+
+    let empty = CommaDelimitedVec::<Ident>::new();
+    assert!(NonEmptyVec::try_from(empty).is_err());
+    let mut token_iter = "x, y".to_token_iter();
+    let delimited = CommaDelimitedVec::<Ident>::parse(&mut token_iter).unwrap();
+    let non_empty = NonEmptyVec::try_from(delimited).unwrap();
+    let vec: Vec<Ident> = non_empty.into();
+    assert_eq!(vec.len(), 2);
+}
+
+#[test]
+fn test_delimited_vec_api() {
+    let mut list: CommaDelimitedVec<LiteralInteger> = (1..=3).map(LiteralInteger::new).collect();
+    assert_tokens_eq!(list, "1, 2, 3");
+
+    for value in list.values_mut() {
+        value.set(value.value() * 10);
+    }
+    list.extend([LiteralInteger::new(40)]);
+    assert_tokens_eq!(list, "10, 20, 30, 40");
+
+    // removing the last value keeps the list without trailing delimiter
+    assert_eq!(list.remove(3).value(), 40);
+    assert_tokens_eq!(list, "10, 20, 30");
+
+    for delimited in &mut list {
+        delimited.delimiter = None;
+    }
+    assert_eq!(list.delimiters().count(), 0);
+
+    // Repeats has the same api
+    let mut repeats: Any<Ident, Comma> = ["a", "b"]
+        .into_iter()
+        .map(|s| Ident::new(s, Span::call_site()))
+        .collect();
+    repeats.push(Ident::new("c", Span::call_site()));
+    assert_tokens_eq!(repeats, "a, b, c");
+    assert_eq!(repeats.iter().len(), 3);
+    assert_eq!(repeats.values().next().unwrap().to_string(), "a");
+}
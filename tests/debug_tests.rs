@@ -47,7 +47,7 @@ fn test_lazy_vec() {
                  Ident{sym: bar, span:bytes(5..8)}
              ],
          terminator: LiteralInteger
-         {literal:Literal{lit:1,span:bytes(9..10)},value:1}}"
+         {literal:Literal{lit:1,span:bytes(9..10)},value:1,suffix:\"\"}}"
             .strip_whitespace()
     );
 }
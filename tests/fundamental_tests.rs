@@ -301,3 +301,53 @@ fn test_debug_impls() {
     let debug_str = format!("{invalid:?}");
     assert!(debug_str.contains("Invalid"));
 }
+
+#[test]
+fn test_parse_iter() {
+    // trailing delimiter
+    let mut token_iter = "a, b, c,".to_token_iter();
+    let idents = token_iter
+        .parse_iter_delimited::<Ident, Comma>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(idents.len(), 3);
+    assert!(EndOfStream::parse(&mut token_iter).is_ok());
+
+    // missing delimiter is an error
+    let mut token_iter = "a, b c".to_token_iter();
+    let mut iter = token_iter.parse_iter_delimited::<Ident, Comma>();
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+    assert_tokens_eq!(token_iter, "c");
+
+    // lazy, stops at the first error
+    let mut token_iter = "1 2 x 3".to_token_iter();
+    let mut iter = token_iter.parse_iter::<LiteralInteger>();
+    assert_eq!(iter.next().unwrap().unwrap().value(), 1);
+    assert_eq!(iter.next().unwrap().unwrap().value(), 2);
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+    assert_tokens_eq!(token_iter, "x 3");
+
+    let mut token_iter = "".to_token_iter();
+    assert_eq!(token_iter.parse_iter::<Ident>().count(), 0);
+
+    // items that consume nothing end the iteration with an error
+    let mut token_iter = "a b 1".to_token_iter();
+    let items = token_iter.parse_iter::<Option<Ident>>().collect::<Vec<_>>();
+    assert_eq!(items.len(), 3);
+    assert!(items[2].is_err());
+    assert_tokens_eq!(token_iter, "1");
+    let mut token_iter = "a".to_token_iter();
+    assert!(token_iter.parse_iter::<Nothing>().all(|item| item.is_err()));
+
+    // with a delimiter the delimiter is progress
+    let mut token_iter = "x, , y".to_token_iter();
+    let items = token_iter
+        .parse_iter_delimited::<Option<Ident>, Comma>()
+        .collect::<Vec<_>>();
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().all(Result::is_ok));
+}
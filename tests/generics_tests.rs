@@ -0,0 +1,147 @@
+//! testing visibility, generics and where clauses
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+#[test]
+fn test_visibility() {
+    let mut token_iter = "pub(self) pub(super) pub(in crate::a) pub(foo) pub x".to_token_iter();
+    assert!(matches!(
+        Visibility::parse(&mut token_iter).unwrap(),
+        Visibility::Restricted { .. }
+    ));
+    assert!(matches!(
+        Visibility::parse(&mut token_iter).unwrap(),
+        Visibility::Restricted { .. }
+    ));
+    let restricted_in = Visibility::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(restricted_in, "pub(in crate::a)");
+    // `pub(foo)` is not a valid restriction, `pub` is taken alone
+    assert!(matches!(
+        Visibility::parse(&mut token_iter).unwrap(),
+        Visibility::Public(_)
+    ));
+    assert_tokens_eq!(ParenthesisGroup::parse(&mut token_iter).unwrap(), "(foo)");
+    assert!(matches!(
+        Visibility::parse(&mut token_iter).unwrap(),
+        Visibility::Public(_)
+    ));
+    assert!(Visibility::parse(&mut token_iter).unwrap().is_inherited());
+    assert_tokens_eq!(token_iter, "x");
+    assert_tokens_eq!(Visibility::default(), "");
+}
+
+#[test]
+fn test_generics() {
+    let generics = Generics::parse_all(&mut "".to_token_iter()).unwrap();
+    assert!(generics.is_empty());
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl(None);
+    assert!(impl_generics.is_empty());
+    assert!(type_generics.is_empty());
+    assert!(where_clause.is_empty());
+
+    let mut token_iter =
+        "< 'a: 'b + 'c, T: ?Sized + Iterator<Item = u8> = Vec<u8> , const N: usize = 4>"
+            .to_token_iter();
+    let generics = Generics::parse_all(&mut token_iter).unwrap();
+    let names: Vec<_> = generics.params.values().map(GenericParam::name).collect();
+    assert_eq!(names, ["a", "T", "N"]);
+    assert_tokens_eq!(
+        generics,
+        "< 'a: 'b + 'c, T: ?Sized + Iterator<Item = u8> = Vec<u8> , const N: usize = 4>"
+    );
+
+    let (impl_generics, type_generics, _) = generics.split_for_impl(None);
+    assert_tokens_eq!(
+        impl_generics,
+        "< 'a: 'b + 'c, T: ?Sized + Iterator<Item = u8> , const N: usize>"
+    );
+    assert_tokens_eq!(type_generics, "< 'a, T, N>");
+
+    // unterminated generics are an error
+    assert!(Generics::parse(&mut "<T".to_token_iter()).is_err());
+}
+
+#[test]
+fn test_fn_and_higher_ranked_bounds() {
+    let mut token_iter =
+        "<F: Fn(u8) -> u8 + Send, G: FnMut(), T: for<'a> Tr<'a>, U: ?Sized + for<'a, 'b> Fn(&'a u8, &'b u8)>"
+            .to_token_iter();
+    let generics = Generics::parse_all(&mut token_iter).unwrap();
+    let names: Vec<_> = generics.params.values().map(GenericParam::name).collect();
+    assert_eq!(names, ["F", "G", "T", "U"]);
+    assert_tokens_eq!(
+        generics,
+        "<F: Fn(u8) -> u8 + Send, G: FnMut(), T: for< 'a> Tr< 'a> , U: ?Sized + for< 'a, 'b> Fn(& 'a u8, & 'b u8)>"
+    );
+
+    let mut token_iter = "for<'a> Fn(&'a str) -> bool".to_token_iter();
+    let TypeBound::Trait {
+        for_lifetimes,
+        fn_args,
+        ..
+    } = TypeBound::parse_all(&mut token_iter).unwrap()
+    else {
+        panic!("expected a trait bound");
+    };
+    assert_eq!(for_lifetimes.unwrap().third.len(), 1);
+    assert_tokens_eq!(fn_args.unwrap().first, "(& 'a str)");
+
+    let mut token_iter = "where F: for<'a> Fn(&'a str) -> Option<&'a str>, {}".to_token_iter();
+    let where_clause = WhereClause::parse(&mut token_iter).unwrap();
+    assert_tokens_eq!(
+        where_clause,
+        "where F: for< 'a> Fn(& 'a str) -> Option< & 'a str> ,"
+    );
+
+    // higher-ranked lifetimes before the bounded type
+    let mut token_iter = "where for<'a> F: Fn(&'a u8), for<'a> &'a T: Send {}".to_token_iter();
+    let where_clause = WhereClause::parse(&mut token_iter).unwrap();
+    let predicates: Vec<_> = where_clause.predicates.values().collect();
+    assert!(matches!(
+        predicates[0],
+        WherePredicate::Type {
+            for_lifetimes: Some(_),
+            ..
+        }
+    ));
+    assert_tokens_eq!(
+        where_clause,
+        "where for< 'a> F: Fn(& 'a u8), for< 'a> & 'a T: Send"
+    );
+}
+
+#[test]
+fn test_where_clause() {
+    let mut token_iter = "where 'a: 'b, T: Clone + 'a, Vec<T>: Send, {}".to_token_iter();
+    let where_clause = WhereClause::parse(&mut token_iter).unwrap();
+    let predicates: Vec<_> = where_clause.predicates.values().collect();
+    assert_eq!(predicates.len(), 3);
+    assert!(matches!(predicates[0], WherePredicate::Lifetime { .. }));
+    assert!(matches!(predicates[2], WherePredicate::Type { .. }));
+    assert_tokens_eq!(token_iter, "{}");
+
+    assert!(WhereClause::parse(&mut "T: Clone".to_token_iter()).is_err());
+}
+
+#[test]
+fn test_split_for_impl_with_quote() {
+    let mut token_iter =
+        "pub struct Wrapper<T: Clone, U = ()> where U: Default { t: T, u: U }".to_token_iter();
+    let vis = Visibility::parse(&mut token_iter).unwrap();
+    let _struct_kw = Ident::parse(&mut token_iter).unwrap();
+    let name = Ident::parse(&mut token_iter).unwrap();
+    let generics = Generics::parse(&mut token_iter).unwrap();
+    let where_clause = Option::<WhereClause>::parse(&mut token_iter).unwrap();
+    let _body = BraceGroup::parse_all(&mut token_iter).unwrap();
+
+    let (impl_generics, type_generics, where_clause) =
+        generics.split_for_impl(where_clause.as_ref());
+    let generated = quote! {
+        impl #impl_generics Clone for #name #type_generics #where_clause {}
+        #vis fn new #impl_generics() {}
+    };
+    assert_tokens_eq!(
+        generated,
+        "impl<T: Clone, U> Clone for Wrapper<T, U> where U: Default {} pub fn new<T: Clone, U>() {}"
+    );
+}
@@ -0,0 +1,64 @@
+//! testing lifetimes and labels
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+keyword! {
+    Static = "static";
+    Underscore = "_";
+}
+
+unsynn! {
+    // `'a: 'b + 'static`
+    struct Outlives {
+        lifetime: Lifetime,
+        colon: Colon,
+        bounds: Vec<Delimited<Lifetime, Plus>>,
+    }
+}
+
+#[test]
+fn test_lifetime() {
+    let mut token_iter = "'a: 'b + 'static".to_token_iter();
+
+    let outlives = Outlives::parse_all(&mut token_iter).unwrap();
+    assert_eq!(outlives.lifetime.name(), "a");
+    assert_eq!(outlives.bounds[1].value.name(), "static");
+    // the tick stays joint with the name
+    assert_tokens_eq!(outlives, str "'a : 'b + 'static");
+
+    let cached = CachedLifetime::parse(&mut "'x".to_token_iter()).unwrap();
+    assert_eq!(cached.as_str(), "'x");
+    assert_eq!(cached.name(), "x");
+}
+
+#[test]
+fn test_lifetime_keywords() {
+    let mut token_iter = "'static '_ 'a".to_token_iter();
+
+    let _ = Lifetime::<Static>::parse(&mut token_iter).unwrap();
+    assert!(Lifetime::<Static>::parse(&mut token_iter).is_err());
+    let underscore = Lifetime::<Underscore>::parse(&mut token_iter).unwrap();
+    assert_eq!(underscore.name(), "_");
+    assert!(Lifetime::<Underscore>::parse(&mut token_iter).is_err());
+
+    // a tick that is not joint is not a lifetime
+    let mut tokens = TokenStream::new();
+    Punct::new('\'', Spacing::Alone).to_tokens(&mut tokens);
+    Ident::new("a", Span::call_site()).to_tokens(&mut tokens);
+    let mut token_iter = tokens.to_token_iter();
+    assert!(<Lifetime>::parse(&mut token_iter).is_err());
+}
+
+#[test]
+fn test_label() {
+    let mut token_iter = "'outer: 'inner :".to_token_iter();
+
+    let label = CachedLabel::parse(&mut token_iter).unwrap();
+    assert_eq!(label.name(), "outer");
+    assert_eq!(label.as_str(), "'outer :");
+    let label = <Label>::parse(&mut token_iter).unwrap();
+    assert_eq!(label.name(), "inner");
+
+    assert_tokens_eq!(Label::new("a"), str "'a :");
+    assert_tokens_eq!(Label::<Static>::default(), str "'static :");
+}
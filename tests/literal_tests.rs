@@ -23,6 +23,14 @@ fn test_character() {
 
     let character = LiteralCharacter::parse(&mut token_iter).unwrap();
     assert_eq!(character.value(), 'x');
+
+    let mut token_iter = r"'\n' '\u{41}' '\'' '\\' '\x7f' 'é'".to_token_iter();
+    for expected in ['\n', 'A', '\'', '\\', '\x7f', 'é'] {
+        assert_eq!(
+            LiteralCharacter::parse(&mut token_iter).unwrap().value(),
+            expected
+        );
+    }
 }
 
 #[test]
@@ -38,12 +46,14 @@ fn test_string() {
 
     let string = LiteralString::parse(&mut token_iter).unwrap();
     assert_eq!(string.value(), "\"this is a string literal\"");
+    assert_eq!(string.unescaped(), "this is a string literal");
 }
 
 #[test]
 fn test_string_new() {
     let string = LiteralString::new("\"this is a string literal\"".to_string());
     assert_eq!(string.value(), "\"this is a string literal\"");
+    assert_tokens_eq!(string, "\"this is a string literal\"");
 }
 
 #[test]
@@ -153,3 +163,202 @@ fn test_string_set_value() {
     assert_eq!(string.value(), "\"new\"");
     assert_eq!(string.as_str(), "new");
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_float() {
+    let mut token_iter = "1.5 1e3 2_0.0_f64 3f32 4 0x1e5".to_token_iter();
+
+    assert_eq!(LiteralFloat::parse(&mut token_iter).unwrap().value(), 1.5);
+    assert_eq!(
+        LiteralFloat::parse(&mut token_iter).unwrap().value(),
+        1000.0
+    );
+    let float = LiteralFloat::parse(&mut token_iter).unwrap();
+    assert_eq!(float.value(), 20.0);
+    assert_eq!(float.suffix(), "f64");
+    assert_tokens_eq!(float, "2_0.0_f64");
+    assert_eq!(LiteralFloat::parse(&mut token_iter).unwrap(), 3.0);
+    // integers are not floats
+    assert!(LiteralFloat::parse(&mut token_iter).is_err());
+    let _ = LiteralInteger::parse(&mut token_iter).unwrap();
+    assert!(LiteralFloat::parse(&mut token_iter).is_err());
+
+    let mut float = LiteralFloat::new(1.0);
+    assert_tokens_eq!(float, "1.0");
+    float.set(0.25);
+    assert_tokens_eq!(float, "0.25");
+}
+
+#[test]
+fn test_integer_radix_suffix() {
+    let mut token_iter =
+        "0xdead_beef 0o777 0b1111_0000 1_000_000usize 0x1f32 12abc".to_token_iter();
+
+    assert_eq!(
+        LiteralInteger::parse(&mut token_iter).unwrap().value(),
+        0xdead_beef
+    );
+    assert_eq!(
+        LiteralInteger::parse(&mut token_iter).unwrap().value(),
+        0o777
+    );
+    assert_eq!(
+        LiteralInteger::parse(&mut token_iter).unwrap().value(),
+        0b1111_0000
+    );
+    let int = LiteralInteger::parse(&mut token_iter).unwrap();
+    assert_eq!(int.value(), 1_000_000);
+    assert_eq!(int.suffix(), "usize");
+    assert_tokens_eq!(int, "1_000_000usize");
+    // f32 is not a suffix but hex digits
+    let int = LiteralInteger::parse(&mut token_iter).unwrap();
+    assert_eq!(int.value(), 0x1f32);
+    assert_eq!(int.suffix(), "");
+    assert!(LiteralInteger::parse(&mut token_iter).is_err());
+}
+
+#[test]
+fn test_string_escapes() {
+    let mut token_iter = r###" "\x41\u{1F600}\\\"\'\0" "a\
+        b" r"\n" r##"#"#"## "###
+        .to_token_iter();
+
+    let string = LiteralString::parse(&mut token_iter).unwrap();
+    assert_eq!(string.unescaped(), "A\u{1F600}\\\"'\0");
+    assert!(!string.is_raw());
+    let string = LiteralString::parse(&mut token_iter).unwrap();
+    assert_eq!(string.unescaped(), "ab");
+    let string = LiteralString::parse(&mut token_iter).unwrap();
+    assert_eq!(string.unescaped(), "\\n");
+    let string = LiteralString::parse(&mut token_iter).unwrap();
+    assert_eq!(string.unescaped(), "#\"#");
+    assert_eq!(string.value(), r###"r##"#"#"##"###);
+    assert_tokens_eq!(string, r###"r##"#"#"##"###);
+
+    // from_str escapes on output and round trips
+    let string = LiteralString::from_str("tab\t\"quote\"");
+    let mut token_iter = string.to_token_iter();
+    assert_eq!(
+        LiteralString::parse(&mut token_iter).unwrap().unescaped(),
+        "tab\t\"quote\""
+    );
+}
+
+#[test]
+fn test_byte_literals() {
+    let mut token_iter =
+        r##" b'\'' b'\x80' b"a\tb\xff" br#"\x"# c"caf\u{e9}" cr"\0" "##.to_token_iter();
+
+    assert_eq!(LiteralByte::parse(&mut token_iter).unwrap(), b'\'');
+    assert_eq!(LiteralByte::parse(&mut token_iter).unwrap(), 0x80);
+    let bytes = LiteralByteString::parse(&mut token_iter).unwrap();
+    assert_eq!(bytes, &b"a\tb\xff"[..]);
+    assert!(!bytes.is_raw());
+    let bytes = LiteralByteString::parse(&mut token_iter).unwrap();
+    assert_eq!(bytes.value(), br"\x");
+    assert_tokens_eq!(bytes, r##"br#"\x"#"##);
+    let cstr = LiteralCString::parse(&mut token_iter).unwrap();
+    assert_eq!(cstr, c"caf\u{e9}");
+    let cstr = LiteralCString::parse(&mut token_iter).unwrap();
+    assert!(cstr.is_raw());
+    assert_eq!(cstr.as_bytes(), br"\0");
+
+    // plain strings and characters are rejected
+    for source in [r#""a""#, "'a'"] {
+        assert!(LiteralByte::parse(&mut source.to_token_iter()).is_err());
+        assert!(LiteralByteString::parse(&mut source.to_token_iter()).is_err());
+        assert!(LiteralCString::parse(&mut source.to_token_iter()).is_err());
+    }
+
+    // constructed literals round trip
+    let bytes = LiteralByteString::from_bytes([0, 1, b'"', 0xfe]);
+    let mut token_iter = bytes.to_token_iter();
+    assert_eq!(
+        LiteralByteString::parse(&mut token_iter).unwrap().value(),
+        &[0, 1, b'"', 0xfe]
+    );
+    let cstr = LiteralCString::from_c_str(c"line\nfeed");
+    let mut token_iter = cstr.to_token_iter();
+    assert_eq!(
+        LiteralCString::parse(&mut token_iter).unwrap().value(),
+        c"line\nfeed"
+    );
+}
+
+#[test]
+fn test_signed_integer() {
+    let mut token_iter = "-0x80 +5 9 -170141183460469231731687303715884105729".to_token_iter();
+
+    let min = LiteralSignedInteger::parse(&mut token_iter).unwrap();
+    assert_eq!(min, -128);
+    assert!(min.is_negative());
+    assert_eq!(min.literal().value(), 128);
+    let plus = LiteralSignedInteger::parse(&mut token_iter).unwrap();
+    assert_eq!(plus, 5);
+    assert!(matches!(plus.sign(), Some(Either::First(_))));
+    assert_tokens_eq!(plus, "+5");
+    let unsigned = LiteralSignedInteger::parse(&mut token_iter).unwrap();
+    assert!(unsigned.sign().is_none());
+    assert_eq!(unsigned, 9);
+    assert!(LiteralSignedInteger::parse(&mut token_iter).is_err());
+
+    // the full range of the rust types is supported
+    let mut token_iter = "-128 -128i8 128i8".to_token_iter();
+    assert_eq!(i8::parse(&mut token_iter).unwrap(), i8::MIN);
+    assert_eq!(i8::parse(&mut token_iter).unwrap(), i8::MIN);
+    assert!(i8::parse(&mut token_iter).is_err());
+    assert_tokens_eq!(i8::MIN, "-128");
+
+    let mut token_iter = "-7 7".to_token_iter();
+    assert!(ConstSignedInteger::<7>::parse(&mut token_iter).is_err());
+    assert_eq!(
+        ConstSignedInteger::<-7>::parse(&mut token_iter)
+            .unwrap()
+            .value(),
+        -7
+    );
+    assert!(ConstSignedInteger::<-7>::parse(&mut token_iter).is_err());
+    assert_tokens_eq!(ConstSignedInteger::<-7>::default(), "-7");
+}
+
+#[test]
+fn test_literal_kind() {
+    let mut token_iter =
+        r##" 0x1e 1e3 2f32 3u8 br#"raw"# cr"raw" r#"raw"# ident "##.to_token_iter();
+
+    let kind = LiteralKind::parse(&mut token_iter).unwrap();
+    assert_eq!(kind.integer(), Some(30));
+    assert_eq!(kind.float(), None);
+    assert_eq!(
+        LiteralKind::parse(&mut token_iter).unwrap().float(),
+        Some(1000.0)
+    );
+    assert!(matches!(
+        LiteralKind::parse(&mut token_iter).unwrap(),
+        LiteralKind::Float(float) if float.suffix() == "f32"
+    ));
+    assert!(matches!(
+        LiteralKind::parse(&mut token_iter).unwrap(),
+        LiteralKind::Integer(int) if int.suffix() == "u8"
+    ));
+    let kind = LiteralKind::parse(&mut token_iter).unwrap();
+    assert!(kind.is_raw());
+    assert_eq!(kind.byte_string(), Some(&b"raw"[..]));
+    assert_tokens_eq!(kind, r##"br#"raw"#"##);
+    let kind = LiteralKind::parse(&mut token_iter).unwrap();
+    assert!(matches!(kind, LiteralKind::RawCString(_)));
+    let kind = LiteralKind::parse(&mut token_iter).unwrap();
+    assert!(matches!(kind, LiteralKind::RawString(_)));
+    assert!(LiteralKind::parse(&mut token_iter).is_err());
+
+    let mut token_iter = r"'\n' '\u{41}'".to_token_iter();
+    assert_eq!(
+        LiteralKind::parse(&mut token_iter).unwrap().character(),
+        Some('\n')
+    );
+    assert_eq!(
+        LiteralKind::parse(&mut token_iter).unwrap().character(),
+        Some('A')
+    );
+}
@@ -28,6 +28,39 @@ unsynn! {
     {
         t: T
     }
+
+    // transformed fields
+    struct Mapped {
+        name: Map<Ident, ToUppercase>,
+        value: Map<LiteralString, ToLowercase>,
+        items: BracketGroupContaining<Map<CommaDelimitedVec<Ident>, IntoVec>>,
+    }
+
+    // exact literal constants
+    literal Hello = "hello";
+    literal Half = 0.5;
+    literal NegHalf = -0.5;
+    literal False = false;
+    literal Answer = 42;
+    literal Neg = -7;
+    literal Letter = 'x';
+    literal Newline = '\n';
+    literal Byte = b'z';
+    literal Bytes = b"\x00\xff";
+    literal CStr = c"cstr";
+
+    struct Literals {
+        hello: Hello,
+        half: Half,
+        neg_half: NegHalf,
+        no: False,
+        answer: Answer,
+        neg: Neg,
+        letter: Letter,
+        byte: Byte,
+        bytes: Bytes,
+        cstr: CStr,
+    }
 }
 
 // Bug in 0.0.17, parsing Enum::Two consumes the Plus token
@@ -83,3 +116,82 @@ fn test_quote_macro() {
     let quoted = quote! { let a = (#ast);};
     assert_tokens_eq!(quoted, "let a = (1+2);");
 }
+
+#[test]
+fn test_map_fields() {
+    let mut i = r#"foo "Hello World" [a, b, c]"#.to_token_iter();
+
+    let parsed = i.parse::<Mapped>().unwrap();
+    assert_eq!(parsed.name.to_string(), "FOO");
+    assert_eq!(parsed.value.as_str(), "hello world");
+    assert_eq!(parsed.items.content.len(), 3);
+    assert_tokens_eq!(parsed, r#"FOO "hello world" [a b c]"#);
+}
+
+#[test]
+fn test_literal_macro() {
+    let mut token_iter = r#"
+        "he\x6clo" 0.5 -0.5 false 0x2a -7 'x' b'z' b"\0\xFF" cr"cstr"
+    "#
+    .to_token_iter();
+    let literals = Literals::parse_all(&mut token_iter).unwrap();
+    assert_eq!(literals.hello.unescaped(), "hello");
+    assert_eq!(literals.answer.value(), 42);
+    assert_tokens_eq!(literals.half, "0.5");
+    assert!(*literals.neg_half < 0.0);
+    assert!(!*literals.no);
+    assert_eq!(Neg::VALUE, -7);
+    assert_eq!(Bytes::VALUE, &[0, 0xff]);
+    assert!(literals.cstr.is_raw());
+
+    // only the exact values match
+    let mut token_iter = r#" "Hello" 0.25 true 43 7 'y' b'Z' b"" c"" "#.to_token_iter();
+    assert!(Hello::parse(&mut token_iter).is_err());
+    let _ = LiteralString::parse(&mut token_iter).unwrap();
+    assert!(Half::parse(&mut token_iter).is_err());
+    let _ = LiteralFloat::parse(&mut token_iter).unwrap();
+    assert!(False::parse(&mut token_iter).is_err());
+    let _ = bool::parse(&mut token_iter).unwrap();
+    assert!(Answer::parse(&mut token_iter).is_err());
+    let _ = LiteralInteger::parse(&mut token_iter).unwrap();
+    assert!(Neg::parse(&mut token_iter).is_err());
+    let _ = LiteralInteger::parse(&mut token_iter).unwrap();
+    assert!(Letter::parse(&mut token_iter).is_err());
+    let _ = LiteralCharacter::parse(&mut token_iter).unwrap();
+    assert!(Byte::parse(&mut token_iter).is_err());
+    let _ = LiteralByte::parse(&mut token_iter).unwrap();
+    assert!(Bytes::parse(&mut token_iter).is_err());
+    let _ = LiteralByteString::parse(&mut token_iter).unwrap();
+    assert!(CStr::parse(&mut token_iter).is_err());
+
+    // defaults create the constants
+    let default = quote! {
+        #{Some(Hello::default())} #{Some(NegHalf::default())} #{Some(False::default())}
+        #{Some(Neg::default())} #{Some(Letter::default())} #{Some(Byte::default())}
+        #{Some(CStr::default())}
+    };
+    assert_tokens_eq!(default, r#" "hello" -0.5 false -7 'x' b'z' c"cstr" "#);
+    let bytes = Bytes::default();
+    let mut token_iter = bytes.to_token_iter();
+    assert_eq!(Bytes::parse(&mut token_iter).unwrap().value(), &[0, 0xff]);
+
+    // escaped characters match regardless of the escape used
+    let mut token_iter = r"'\n' '\x0a' '\u{a}' 'n'".to_token_iter();
+    assert_tokens_eq!(Newline::parse(&mut token_iter).unwrap(), r"'\n'");
+    assert_tokens_eq!(Newline::parse(&mut token_iter).unwrap(), r"'\x0a'");
+    assert_tokens_eq!(Newline::parse(&mut token_iter).unwrap(), r"'\u{a}'");
+    assert!(Newline::parse(&mut token_iter).is_err());
+}
+
+#[test]
+fn test_operator_group_macro() {
+    unsynn! {
+        operator Sign = [Plus, Minus];
+        operator NotSign != [Sign];
+    }
+
+    let mut tokens = "- * +".to_token_iter();
+    assert_eq!(Sign::parse(&mut tokens).unwrap().as_str(), "-");
+    assert_eq!(NotSign::parse(&mut tokens).unwrap().as_str(), "*");
+    assert_eq!(Sign::parse(&mut tokens).unwrap().as_ref(), "+");
+}
@@ -0,0 +1,114 @@
+//! testing meta argument lists
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+meta_args! {
+    #[derive(Debug)]
+    struct Args {
+        name: LiteralString,
+        skip: bool,
+        rename: Option<LiteralString>,
+        count: OrDefault<u32, ConstInteger<1>>,
+        nested: Option<Nested>,
+        state: HiddenState<Vec<u8>>,
+    }
+
+    #[derive(Debug)]
+    struct Nested {
+        a: Ident,
+        b: Option<i64>,
+    }
+}
+
+unsynn! {
+    #[derive(Debug)]
+    meta struct InUnsynn {
+        flag: bool,
+    }
+}
+
+#[test]
+fn test_meta_args() {
+    let mut token_iter = r#"nested(a = foo), count = 3, skip, name = "x""#.to_token_iter();
+    let args = Args::parse_all(&mut token_iter).unwrap();
+    assert_eq!(args.name.as_str(), "x");
+    assert!(args.skip);
+    assert!(args.rename.is_none());
+    assert_tokens_eq!(args.count, "3");
+    assert!(args.state.is_empty());
+    let nested = args.nested.as_ref().unwrap();
+    assert_eq!(nested.a.to_string(), "foo");
+    assert!(nested.b.is_none());
+    assert_tokens_eq!(args, r#"name = "x", skip, count = 3, nested(a = foo)"#);
+}
+
+#[test]
+fn test_meta_args_defaults() {
+    let mut token_iter = r#"name = "x","#.to_token_iter();
+    let args = Args::parse_all(&mut token_iter).unwrap();
+    assert!(!args.skip);
+    assert_tokens_eq!(args.count, "1");
+    assert!(args.nested.is_none());
+    assert_tokens_eq!(args, r#"name = "x""#);
+}
+
+#[test]
+fn test_meta_args_errors() {
+    // unknown
+    let mut token_iter = r#"name = "x", frob"#.to_token_iter();
+    let err = Args::parse_all(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("unknown argument `frob`"));
+
+    // hidden state is not an argument
+    let mut token_iter = r#"name = "x", state = 1"#.to_token_iter();
+    assert!(Args::parse_all(&mut token_iter).is_err());
+
+    // duplicate
+    let mut token_iter = r#"name = "x", skip, skip"#.to_token_iter();
+    let err = Args::parse_all(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("duplicate argument `skip`"));
+
+    // missing
+    let mut token_iter = "skip".to_token_iter();
+    let err = Args::parse_all(&mut token_iter).unwrap_err();
+    assert!(err.to_string().contains("missing argument `name`"));
+    // the error points at the argument list
+    assert_eq!(err.failed_at().unwrap().to_string(), "skip");
+
+    // wrong shape
+    let mut token_iter = "name".to_token_iter();
+    assert!(Args::parse_all(&mut token_iter).is_err());
+    let mut token_iter = r#"name = "x", nested = 1"#.to_token_iter();
+    assert!(Args::parse_all(&mut token_iter).is_err());
+
+    // wrong value type
+    let mut token_iter = "name = 1".to_token_iter();
+    assert!(Args::parse_all(&mut token_iter).is_err());
+
+    // conversion errors point at the entry in the original tokens
+    let mut token_iter = r#"skip, name = "x", count = y, rename = "z""#.to_token_iter();
+    let err = Args::parse_all(&mut token_iter).unwrap_err();
+    assert_eq!(err.failed_at().unwrap().to_string(), "y");
+    assert_eq!(err.pos(), 6);
+}
+
+#[test]
+fn test_meta_in_unsynn() {
+    let mut token_iter = "flag".to_token_iter();
+    let args = InUnsynn::parse_all(&mut token_iter).unwrap();
+    assert!(args.flag);
+}
+
+#[test]
+fn test_meta_grammar() {
+    let mut token_iter = "a = -1 + 2, b, c(d, e = f)".to_token_iter();
+    let args = MetaArgs::parse_all(&mut token_iter).unwrap();
+    assert_eq!(args.len(), 3);
+    let Meta::NameValue(ref a) = args[0].value else {
+        panic!()
+    };
+    assert_tokens_eq!(a.value, "-1 + 2");
+    assert!(args[0].value.parse_value::<i32>().is_err());
+    assert!(args[1].value.parse_value::<i32>().is_err());
+    assert_tokens_eq!(args, "a = -1 + 2, b, c(d, e = f)");
+}
@@ -0,0 +1,130 @@
+//! testing paths and type expressions
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+/// parses `source` as `T`, checks that nothing is left and that the tokens parse again
+fn round_trip<T: Parse + ToTokens>(source: &str) -> T {
+    let mut token_iter = source.to_token_iter();
+    let parsed = T::parse_all(&mut token_iter).unwrap();
+    let again = T::parse_all(&mut parsed.to_token_iter()).unwrap();
+    assert_tokens_eq!(parsed, again);
+    parsed
+}
+
+#[test]
+fn test_simple_path() {
+    let path: SimplePath = round_trip("a::b::c");
+    assert!(path.leading.is_none());
+    assert_eq!(path.segments.len(), 3);
+    assert_eq!(path.last_segment().unwrap().as_str(), "c");
+    assert!(round_trip::<SimplePath>("Self").is_ident("Self"));
+    assert!(!path.is_ident("c"));
+
+    // generics are not part of simple paths
+    let mut token_iter = "Vec<T>".to_token_iter();
+    assert!(SimplePath::parse_all(&mut token_iter).is_err());
+    assert!(SimplePath::parse(&mut "".to_token_iter()).is_err());
+}
+
+#[test]
+fn test_generic_path() {
+    let path: GenericPath =
+        round_trip("::std::collections::HashMap<K, Vec<Box<dyn_trait>>, 'a, 3, {N + 1}>");
+    assert!(path.leading.is_some());
+    let last = path.last_segment().unwrap();
+    assert_eq!(last.ident, "HashMap");
+    let args: Vec<_> = last.generics.as_ref().unwrap().args.values().collect();
+    assert!(matches!(args[0], GenericArg::Type(_)));
+    assert!(matches!(args[2], GenericArg::Lifetime(_)));
+    assert!(matches!(args[3], GenericArg::Literal(_)));
+    assert!(matches!(args[4], GenericArg::Block(_)));
+
+    let path: GenericPath = round_trip("Vec::<u8>::with_capacity");
+    assert!(path.segments[0]
+        .value
+        .generics
+        .as_ref()
+        .unwrap()
+        .turbofish
+        .is_some());
+    assert_eq!(path.last_segment().unwrap().ident, "with_capacity");
+
+    let path: GenericPath = round_trip("Iterator<Item = &'static str>");
+    let args = &path.last_segment().unwrap().generics.as_ref().unwrap().args;
+    assert!(matches!(&args[0].value, GenericArg::Binding { ident, .. } if *ident == "Item"));
+}
+
+#[test]
+fn test_type_expr() {
+    let ty: TypeExpr = round_trip("&&mut T");
+    let TypeExpr::Reference { ty, mutability, .. } = ty else {
+        panic!()
+    };
+    assert!(mutability.is_none());
+    assert!(matches!(
+        *ty,
+        TypeExpr::Reference {
+            mutability: Some(_),
+            ..
+        }
+    ));
+
+    assert!(matches!(
+        round_trip("*mut u8"),
+        TypeExpr::Pointer {
+            mutability: Either::Second(_),
+            ..
+        }
+    ));
+    assert!(matches!(round_trip("()"), TypeExpr::Tuple(unit) if unit.content.is_empty()));
+    assert!(matches!(round_trip("[[u8; 4]; N * 2]"), TypeExpr::Array(_)));
+    assert!(matches!(round_trip("[&str]"), TypeExpr::Slice(_)));
+    assert!(matches!(round_trip("!"), TypeExpr::Never(_)));
+    assert_eq!(
+        round_trip::<TypeExpr>("Result<(), Box<[u8]>>")
+            .last_segment()
+            .unwrap()
+            .ident,
+        "Result"
+    );
+    assert!(round_trip::<TypeExpr>("&T").as_path().is_none());
+
+    // trait objects, impl trait and function pointers
+    let TypeExpr::Reference { ty, .. } = round_trip("&'a dyn Fn(&str) -> bool") else {
+        panic!()
+    };
+    assert!(matches!(*ty, TypeExpr::TraitObject { ref bounds, .. } if bounds.len() == 1));
+    assert!(matches!(
+        round_trip("impl Iterator<Item = u8> + Send + 'static"),
+        TypeExpr::ImplTrait { bounds, .. } if bounds.len() == 3
+    ));
+    let TypeExpr::FnPointer {
+        for_lifetimes,
+        args,
+        ..
+    } = round_trip("for<'a> fn(&'a u8, u8) -> u8")
+    else {
+        panic!()
+    };
+    assert!(for_lifetimes.is_some());
+    assert_eq!(args.first.content.len(), 2);
+    assert!(args.second.is_some());
+    assert!(matches!(round_trip("fn()"), TypeExpr::FnPointer { .. }));
+    round_trip::<TypeExpr>("Box<dyn Error + Send + Sync>");
+
+    // incomplete or malformed types are errors
+    for incomplete in [
+        "&",
+        "*u8",
+        "Vec<T",
+        "[T, U]",
+        "fn(x: u8)",
+        "<T as Trait>::X",
+    ] {
+        let mut token_iter = incomplete.to_token_iter();
+        assert!(
+            TypeExpr::parse_all(&mut token_iter).is_err(),
+            "{incomplete}"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! testing validating predicates
+#![allow(clippy::unwrap_used)]
+use unsynn::*;
+
+#[test]
+fn test_validated_case() {
+    let mut token_iter = "_private r#type fooBar Foo Foo_Bar foo__bar".to_token_iter();
+
+    assert!(<Validated<Ident, IsSnakeCase>>::parse(&mut token_iter).is_ok());
+    assert!(<Validated<CachedIdent, IsSnakeCase>>::parse(&mut token_iter).is_ok());
+    assert!(<Validated<Ident, IsSnakeCase>>::parse(&mut token_iter).is_err());
+    assert!(<Validated<Ident, IsCamelCase>>::parse(&mut token_iter).is_err());
+    // failed validation does not consume tokens
+    assert_tokens_eq!(token_iter, "fooBar Foo Foo_Bar foo__bar");
+    let _ = Ident::parse(&mut token_iter).unwrap();
+    assert!(<Validated<Ident, IsCamelCase>>::parse(&mut token_iter).is_ok());
+    assert!(<Validated<Ident, IsCamelCase>>::parse(&mut token_iter).is_err());
+    assert!(<Validated<Ident, IsSnakeCase>>::parse(&mut token_iter).is_err());
+}
+
+#[test]
+fn test_validated_in_range() {
+    let mut token_iter = "10 11".to_token_iter();
+
+    assert!(<Validated<LiteralInteger, InRange<1, 10>>>::parse(&mut token_iter).is_ok());
+    let err = <Validated<LiteralInteger, InRange<1, 10>>>::parse(&mut token_iter).unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::Other { .. }));
+    assert!(err.to_string().contains("11 is not in range 1..=10"));
+    assert_tokens_eq!(token_iter, "11");
+}
+
+#[test]
+fn test_validated_non_empty() {
+    let mut token_iter = r#" "x" "" [a] [] "#.to_token_iter();
+
+    assert!(<Validated<LiteralString, NonEmpty>>::parse(&mut token_iter).is_ok());
+    assert!(<Validated<LiteralString, NonEmpty>>::parse(&mut token_iter).is_err());
+    let _ = LiteralString::parse(&mut token_iter).unwrap();
+    let list = <BracketGroupContaining<Validated<CommaDelimitedVec<Ident>, NonEmpty>>>::parse(
+        &mut token_iter,
+    )
+    .unwrap();
+    assert_tokens_eq!(list, "[a]");
+    assert!(
+        <BracketGroupContaining<Validated<CommaDelimitedVec<Ident>, NonEmpty>>>::parse(
+            &mut token_iter
+        )
+        .is_err()
+    );
+}
@@ -159,3 +159,137 @@ fn test_lifetime_tick() {
     let tick = LifetimeTick::parse(&mut tokens).unwrap();
     assert_eq!(tick.as_char(), '\'');
 }
+
+operator! {
+    LongArrow = "<===>";
+    VeryLong = "+-*/%^&|";
+    Longest = "<<<<<<<<<<==========";
+}
+
+#[test]
+fn test_long_operator() {
+    let mut tokens = "<===> +-*/%^&| <=== >".to_token_iter();
+    let arrow = LongArrow::parse(&mut tokens).unwrap();
+    assert_tokens_eq!(arrow, str "<===>");
+    assert_eq!(LongArrow::OPERATOR, "<===>");
+    assert_tokens_eq!(VeryLong::parse(&mut tokens).unwrap(), "+-*/%^&|");
+    // the inner characters must be joint
+    assert!(LongArrow::parse(&mut tokens).is_err());
+    assert_eq!(format!("{arrow:?}"), "Operator<'<===>'>");
+
+    assert_eq!(Longest::OPERATOR.len(), OPERATOR_MAX_LEN);
+    let longest = Longest::new().to_token_stream();
+    let mut tokens = longest.to_token_iter();
+    assert_eq!(
+        Longest::parse_all(&mut tokens).unwrap().as_str(),
+        Longest::OPERATOR
+    );
+
+    // operators are aliases of `Operator`
+    let plus: Operator<'+'> = Plus::new();
+    assert_eq!(plus, Operator::<'+'>);
+    assert_eq!(PlusEq::OPERATOR, Operator::<'+', '='>::OPERATOR);
+}
+
+#[test]
+fn test_dyn_operator() {
+    let arrow = DynOperator::new("<===>");
+    assert_tokens_eq!(arrow, str "<===>");
+    // emits the same spacing as a static operator
+    assert_eq!(
+        arrow.to_token_stream().to_string(),
+        LongArrow::new().to_token_stream().to_string()
+    );
+
+    let mut tokens = "<===> <== => +".to_token_iter();
+    assert_eq!(arrow.parse_exact(&mut tokens).unwrap(), "<===>");
+    assert!(arrow.parse_exact(&mut tokens).is_err());
+    assert_eq!(DynOperator::parse(&mut tokens).unwrap(), "<==");
+    assert_eq!(
+        DynOperator::new("=>").parse_exact(&mut tokens).unwrap(),
+        "=>"
+    );
+    assert_eq!(DynOperator::parse(&mut tokens).unwrap(), "+");
+    assert!(DynOperator::parse(&mut tokens).is_err());
+}
+
+#[test]
+#[should_panic = "not a valid operator"]
+fn test_dyn_operator_invalid() {
+    let _ = DynOperator::new("<a>");
+}
+
+operator! {
+    Compare = [Lt, Gt, Le, Ge, "==", "!="];
+    ShiftAssign = [ShlEq, ShrEq, "<<<="];
+    CompareOrShift = [Compare, ShiftAssign, Compare];
+    NotCompare != [Compare];
+}
+
+#[test]
+fn test_operator_group() {
+    let mut tokens = "<<<= >= > = <".to_token_iter();
+    let op = CompareOrShift::parse(&mut tokens).unwrap();
+    assert_eq!(op.as_str(), "<<<=");
+    assert_tokens_eq!(op, str "<<<=");
+    assert_eq!(Compare::parse(&mut tokens).unwrap().as_str(), ">=");
+    // `> =` is not `>=`
+    assert_eq!(Compare::parse(&mut tokens).unwrap().as_str(), ">");
+    assert!(Compare::parse(&mut tokens).is_err());
+    assert_eq!(NotCompare::parse(&mut tokens).unwrap().as_str(), "=");
+    assert!(NotCompare::parse(&mut tokens).is_err());
+    assert_eq!(
+        Compare::parse(&mut tokens).unwrap(),
+        Compare::parse(&mut "<".to_token_iter()).unwrap()
+    );
+}
+
+#[test]
+fn test_operator_group_error() {
+    let mut tokens = "+".to_token_iter();
+    let err = Compare::parse(&mut tokens).unwrap_err();
+    assert!(err.expected_type_name().ends_with("Compare"));
+}
+
+#[test]
+fn test_punct_spaced() {
+    let mut tokens = "a--b - -c".to_token_iter();
+    let _ = Ident::parse(&mut tokens).unwrap();
+    let first = PunctSpaced::<'-'>::parse(&mut tokens).unwrap();
+    assert_eq!(first.spacing(), Spacing::Joint);
+    let second = PunctSpaced::<'-'>::parse(&mut tokens).unwrap();
+    assert_eq!(second.spacing(), Spacing::Alone);
+    assert_tokens_eq!(
+        Cons::<PunctSpaced<'-'>, PunctSpaced<'-'>>::parse(&mut "--".to_token_iter()).unwrap(),
+        "--"
+    );
+    assert_tokens_eq!(PunctSpaced::<'-'>::default(), str "-");
+    assert!(PunctSpaced::<'+'>::parse(&mut tokens).is_err());
+}
+
+#[test]
+fn test_any_spacing() {
+    let mut tokens = "< = = => + =".to_token_iter();
+    assert!(Le::parse(&mut tokens.clone()).is_err());
+    assert_tokens_eq!(AnySpacing::<Le>::parse(&mut tokens).unwrap(), "<=");
+    assert_tokens_eq!(
+        AnySpacing::<Operator<'=', '='>>::parse(&mut tokens).unwrap(),
+        "=="
+    );
+    assert_tokens_eq!(
+        AnySpacing::<Operator<'>'>>::parse(&mut tokens).unwrap(),
+        ">"
+    );
+    let group = AnySpacing::<Compare>::parse(&mut "> =".to_token_iter()).unwrap();
+    assert_eq!(group.as_str(), ">=");
+    assert_tokens_eq!(group, ">=");
+    assert!(DynOperator::new("+=")
+        .parse_exact(&mut tokens.clone())
+        .is_err());
+    assert_eq!(
+        DynOperator::new("+=")
+            .parse_exact_any_spacing(&mut tokens)
+            .unwrap(),
+        "+="
+    );
+}
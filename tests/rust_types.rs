@@ -80,3 +80,61 @@ fn test_char_to_tokens() {
     'x'.to_tokens(&mut tokens);
     assert_eq!(tokens.to_string(), "'x'");
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_float_parse() {
+    let mut tokens = "-1.5 +2.5e2 0.1f32".to_token_iter();
+    assert_eq!(f64::parse(&mut tokens).unwrap(), -1.5);
+    assert_eq!(f64::parse(&mut tokens).unwrap(), 250.0);
+    assert_eq!(f32::parse(&mut tokens).unwrap(), 0.1);
+
+    let mut tokens = "1".to_token_iter();
+    assert!(f64::parse(&mut tokens).is_err());
+
+    // out of range values are rejected instead of becoming infinite
+    assert!(f64::parse(&mut "1e400".to_token_iter()).is_err());
+    assert!(f64::parse(&mut "-1e400".to_token_iter()).is_err());
+    assert!(f32::parse(&mut "1e39".to_token_iter()).is_err());
+    assert_eq!(f64::parse(&mut "1e39".to_token_iter()).unwrap(), 1e39);
+
+    // suffix must match the target type
+    assert!(f64::parse(&mut "1.5f32".to_token_iter()).is_err());
+    assert!(f32::parse(&mut "1.5f64".to_token_iter()).is_err());
+    assert_eq!(f64::parse(&mut "1.5f64".to_token_iter()).unwrap(), 1.5);
+
+    // f32 is parsed from the literal text, not rounded twice through f64
+    let mut tokens = "1.00000017881393432617187499".to_token_iter();
+    assert_eq!(
+        f32::parse(&mut tokens).unwrap(),
+        f32::from_bits(0x3f80_0001)
+    );
+}
+
+#[test]
+fn test_float_to_tokens() {
+    assert_tokens_eq!(-1.5f64, "- 1.5");
+    assert_tokens_eq!(2.0f64, "2.0");
+    assert_tokens_eq!(&0.1f32, "0.1");
+    // round trip
+    let mut tokens = (-0.3f64).to_token_iter();
+    assert_tokens_eq!(f64::parse(&mut tokens).unwrap(), "-0.3");
+
+    // values without a literal representation
+    assert_tokens_eq!(f64::NAN, "f64::NAN");
+    assert_tokens_eq!(f32::INFINITY, "f32::INFINITY");
+    assert_tokens_eq!(&f64::NEG_INFINITY, "- f64::INFINITY");
+}
+
+#[test]
+fn test_integer_suffix() {
+    let mut tokens = "42u8 0xffu8 -1i32 7u16 7i64".to_token_iter();
+    assert_eq!(u8::parse(&mut tokens).unwrap(), 42);
+    assert_eq!(u8::parse(&mut tokens).unwrap(), 255);
+    assert_eq!(i32::parse(&mut tokens).unwrap(), -1);
+    let err = u8::parse(&mut tokens).unwrap_err();
+    assert!(err.to_string().contains("expected suffix `u8`, got `u16`"));
+    assert_eq!(u16::parse(&mut tokens).unwrap(), 7);
+    assert!(i32::parse(&mut tokens).is_err());
+    assert_eq!(i64::parse(&mut tokens).unwrap(), 7);
+}